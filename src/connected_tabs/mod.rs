@@ -0,0 +1,1428 @@
+use crate::*;
+
+pub mod tab;
+pub mod style;
+pub use tab::*;
+pub use style::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Widget for switching between multiple connected tabs,
+/// current tab can be stored in `Ui` memory, a specified `&mut usize`,
+/// or as the value of the tab itself (see [ConnectedTabs::from_values]).
+/// 
+/// # Examples
+/// ```
+/// use egui_extended::prelude::*;
+/// use egui::*;
+/// 
+/// egui::__run_test_ui(|ui|
+/// {
+/// 	ui.vertical_centered(|ui|
+/// 	{
+/// 		ConnectedTabs::new("example_tabs", false)
+/// 			.tab("Tab #1")
+/// 			.tab("Second Tab")
+/// 			.rounding_override(Rounding::same(15.))
+/// 			.show(ui, |ui, i|
+/// 		{
+/// 			Separator::default().shrink(100.).ui(ui);
+///
+/// 			ui.vertical(|ui|
+/// 			{
+/// 				ui.heading(format!("Tab number: {i}"));
+/// 			});
+/// 		});
+/// 	});
+/// });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ConnectedTabs<'a, T = usize>
+{
+	id: Id,
+
+	custom_value_backend: Option<&'a mut T>,
+	tabs: Vec<Tab>,
+	/// The value each tab represents, for index based tabs this is just the index of the tab.
+	values: Vec<T>,
+	/// Whether `values` are just the indices of the tabs, as opposed to values given to `from_values`.
+	values_are_indices: bool,
+	default: usize,
+
+	style: Option<ConnectedTabsStyle>,
+	style_edits: Vec<StyleEdit>,
+	closable: bool,
+	reorderable: bool,
+	detachable: bool,
+	add_button: bool,
+	renamable: bool,
+	context_menu: bool,
+	context_menu_entries: Option<ContextMenuEntries<'a>>,
+	sizing: ConnectedTabsSizing,
+	overflow_menu: bool,
+	wrap: bool,
+	selection_animation: (f32, Easing),
+	content_transition: (ConnectedTabsTransition, f32),
+	transition_fill: Option<Color32>,
+	retain_scroll: bool,
+	#[cfg(feature = "serde")]
+	persist: bool,
+
+	vertical: bool,
+}
+
+/// Changes made to the style by builder functions such as [ConnectedTabs::rounding_override], applied on top of the style in use.
+type StyleEdit = Box<dyn FnOnce(&mut ConnectedTabsStyle)>;
+
+/// User-provided entries of each tab's context menu, see [ConnectedTabs::context_menu_entries].
+type ContextMenuEntries<'a> = Box<dyn FnMut(&mut Ui, usize) + 'a>;
+
+/// How [ConnectedTabs] decides the length of each tab along the strip.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ConnectedTabsSizing
+{
+	/// Every tab is as long as the longest one.
+	#[default]
+	Uniform,
+	/// Every tab is as long as its own contents.
+	FitContent,
+	/// Tabs fill the available space, each getting a share proportional to the length of its contents.
+	Fill,
+}
+
+/// How the contents shown by [ConnectedTabs] change over to a newly selected tab.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ConnectedTabsTransition
+{
+	/// The new contents replace the old ones immediately.
+	#[default]
+	None,
+	/// The old contents fade out while the new ones fade in.
+	CrossFade,
+	/// The old contents slide out while the new ones slide in, along the direction of the strip.
+	Slide,
+}
+
+/// What is happening to the contents of a tab shown by [ConnectedTabs::show_lazy].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabLifecycle
+{
+	/// The tab is shown for the first time, a good time to start loading anything expensive it needs.
+	FirstShown,
+	/// The tab was shown before, and still is.
+	Shown,
+	/// The tab was just switched away from (once any transition away from it has finished), the supplied `Ui` is invisible,
+	/// and the tab won't be shown again until it is selected.
+	Hidden,
+}
+
+/// An entry of the context menu of [ConnectedTabs] that was pressed, see [ConnectedTabs::context_menu].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectedTabsMenuAction
+{
+	/// Every tab except the targeted one should be closed.
+	CloseOthers,
+	/// Every tab after the targeted one should be closed.
+	CloseToTheRight,
+	/// A copy of the targeted tab should be inserted right after it.
+	Duplicate,
+}
+
+/// What happened to a [ConnectedTabs] instance this frame.
+pub struct ConnectedTabsOutput
+{
+	/// The response of the whole tab strip, it is marked as changed if the selected tab changed.
+	pub response: Response,
+	/// The response of each tab, `None` for invisible tabs.
+	pub tab_responses: Vec<Option<Response>>,
+	/// The index of the tab that was selected before this frame.
+	pub previous_index: usize,
+	/// The index of the tab that is selected now.
+	pub index: usize,
+	/// The index of the tab whose close button was pressed this frame, if any.
+	///
+	/// The tab itself isn't removed (except by `show_vec_mut`), that is up to the caller.
+	/// The selected index already shifts as if it was, so a caller that keeps the tab (such as to ask about unsaved changes first)
+	/// should select `previous_index` again, such as through `index`.
+	pub closed: Option<usize>,
+	/// If a tab was dragged to a new position this frame, this is `(from, to)`.
+	///
+	/// The selected index already follows the moved tab, but the tabs themselves are only moved by `show_vec_mut`.
+	pub moved: Option<(usize, usize)>,
+	/// Whether the add button was pressed this frame, see [ConnectedTabs::add_button].
+	///
+	/// No tab is added (except by `show_vec_mut_editable`), that is up to the caller.
+	pub added: bool,
+	/// If a tab was renamed this frame, this is its index and new name, see [ConnectedTabs::renamable].
+	///
+	/// The tab keeps its old name (except with `show_vec_mut_editable`), that is up to the caller.
+	pub renamed: Option<(usize, String)>,
+	/// If an entry of a tab's context menu was pressed this frame, this is the index of the tab and the entry, see [ConnectedTabs::context_menu].
+	///
+	/// Pressing "Close" is reported in `closed` instead. The selected index already follows the selected tab as if the action was carried out,
+	/// but the tabs themselves are only closed by `show_vec_mut`. Duplicating is always up to the caller, so `show_vec_mut` doesn't offer it.
+	pub menu_action: Option<(usize, ConnectedTabsMenuAction)>,
+}
+
+impl ConnectedTabsOutput
+{
+	/// Whether the selected tab changed this frame, including when tabs shifting around changed its index.
+	pub fn changed(&self) -> bool {
+		self.index != self.previous_index
+	}
+
+	/// The response of the tab at `index`, if it exists and is visible.
+	pub fn tab_response(&self, index: usize) -> Option<&Response> {
+		self.tab_responses.get(index)?.as_ref()
+	}
+}
+
+impl<'a> ConnectedTabs<'a>
+{
+	pub fn new(id_source: impl std::hash::Hash, vertical: bool) -> Self {
+		Self::new_with_backend(id_source, vertical, None)
+	}
+
+
+	/// Adds a tab to this instance.
+	pub fn tab(mut self, tab: impl Into<Tab>) -> Self {
+		self.values.push(self.tabs.len());
+		self.tabs.push(tab.into());
+		self
+	}
+	
+	/// Sets the tabs used by this instance.
+	pub fn tabs(mut self, tabs: Vec<WidgetText>) -> Self {
+		self.set_tabs(tabs.into_iter().map(Tab::new).collect());
+		self
+	}
+	
+	pub fn tabs_from_vec<T>(mut self, vec: &Vec<T>, title_fn: impl Fn(&T) -> WidgetText) -> Self {
+		self.set_tabs(vec.iter().map(|value| Tab::new(title_fn(value))).collect());
+		self
+	}
+	
+	crate::builder_set!{/// If this function is not called, the currently selected tab will be stored in tmp ui memory.
+		index => custom_value_backend: &'a mut usize => Some(custom_value_backend)}
+
+	fn set_tabs(&mut self, tabs: Vec<Tab>) {
+		self.values = (0..tabs.len()).collect();
+		self.tabs = tabs;
+	}
+
+	/// Displays this instance the same as `show`, but based on the `Vec` supplied.
+	pub fn show_vec<T>(mut self, ui: &mut Ui, vec: &[T], title_fn: impl Fn(&T) -> WidgetText, mut add_contents: impl FnMut(&mut Ui, &T)) -> ConnectedTabsOutput
+	{
+		self.set_tabs(vec.iter().map(|value| Tab::new(title_fn(value))).collect());
+		self.show(ui, |ui, i| if let Some(value) = vec.get(i) { add_contents(ui, value) })
+	}
+
+	/// Mutable version of `show_vec`, closed tabs are removed from the `Vec` automatically.
+	pub fn show_vec_mut<T>(self, ui: &mut Ui, vec: &mut Vec<T>, title_fn: impl Fn(&T) -> WidgetText, add_contents: impl FnMut(&mut Ui, &mut T)) -> ConnectedTabsOutput
+	{
+		self.show_vec_mut_inner(ui, vec, title_fn, None::<fn() -> T>, |_, _| {}, add_contents)
+	}
+
+	/// Same as `show_vec_mut`, but added tabs are created with `new_fn` and selected, and renamed tabs are renamed with `rename_fn`.
+	/// See `add_button` and `renamable`.
+	pub fn show_vec_mut_editable<T>(self, ui: &mut Ui, vec: &mut Vec<T>, title_fn: impl Fn(&T) -> WidgetText,
+		new_fn: impl FnOnce() -> T, rename_fn: impl FnOnce(&mut T, String), add_contents: impl FnMut(&mut Ui, &mut T)) -> ConnectedTabsOutput
+	{
+		self.show_vec_mut_inner(ui, vec, title_fn, Some(new_fn), rename_fn, add_contents)
+	}
+
+	fn show_vec_mut_inner<T>(mut self, ui: &mut Ui, vec: &mut Vec<T>, title_fn: impl Fn(&T) -> WidgetText,
+		new_fn: Option<impl FnOnce() -> T>, rename_fn: impl FnOnce(&mut T, String), mut add_contents: impl FnMut(&mut Ui, &mut T)) -> ConnectedTabsOutput
+	{
+		self.set_tabs(vec.iter().map(|value| Tab::new(title_fn(value))).collect());
+		// `T` can't be cloned, and the caller can't insert a copy while the selection is kept on the tabs in `vec`, so tabs can't be duplicated
+		let mut output = self.show_tabs(ui, false);
+
+		// The renamed index is from before any tabs were closed or moved
+		if let Some((renamed, name)) = output.renamed.clone() {
+			if let Some(value) = vec.get_mut(renamed) { rename_fn(value, name) }
+		}
+		if let Some(closed) = output.closed {
+			vec.remove(closed);
+		}
+		if let Some((from, to)) = output.moved {
+			let value = vec.remove(from);
+			vec.insert(to, value);
+		}
+		match output.menu_action {
+			Some((i, ConnectedTabsMenuAction::CloseOthers)) => { vec.truncate(i + 1); vec.drain(..i); }
+			Some((i, ConnectedTabsMenuAction::CloseToTheRight)) => vec.truncate(i + 1),
+			_ => (),
+		}
+		if let (true, Some(new_fn)) = (output.added, new_fn) {
+			vec.push(new_fn());
+			self.values.push(vec.len() - 1);
+			self.set_index(ui, vec.len() - 1);
+			output.index = vec.len() - 1;
+			output.response.mark_changed();
+		}
+		self.follow_shifts(ui, &output);
+
+		let index = self.get_index(ui);
+		self.show_in_frame(ui, &output, |ui| self.show_contents(ui, index, |ui, i| if let Some(value) = vec.get_mut(i) { add_contents(ui, value) }));
+		self.show_detached(ui, output.response.rect, |ui, i| if let Some(value) = vec.get_mut(i) { add_contents(ui, value) });
+
+		output
+	}
+}
+
+impl<'a, T: Clone + PartialEq> ConnectedTabs<'a, T>
+{
+	/// Creates tabs that each represent a value, such as the variants of an enum.
+	/// The value of the selected tab is stored in `value` directly, and is what `show` supplies.
+	///
+	/// # Examples
+	/// ```
+	/// use egui_extended::prelude::*;
+	/// use egui::*;
+	///
+	/// #[derive(Clone, PartialEq)]
+	/// enum Page { General, Advanced }
+	///
+	/// let mut page = Page::General;
+	///
+	/// egui::__run_test_ui(|ui|
+	/// {
+	/// 	ConnectedTabs::from_values("page_tabs", false, &mut page, [(Page::General, "General"), (Page::Advanced, "Advanced")])
+	/// 		.show(ui, |ui, page|
+	/// 	{
+	/// 		match page {
+	/// 			Page::General => ui.label("General settings"),
+	/// 			Page::Advanced => ui.label("Advanced settings"),
+	/// 		};
+	/// 	});
+	/// });
+	/// ```
+	pub fn from_values<Title: Into<Tab>>(id_source: impl std::hash::Hash, vertical: bool, value: &'a mut T, tabs: impl IntoIterator<Item = (T, Title)>) -> Self {
+		let (values, tabs): (Vec<T>, Vec<Title>) = tabs.into_iter().unzip();
+
+		Self {
+			values,
+			tabs: tabs.into_iter().map(Into::into).collect(),
+			values_are_indices: false,
+			..Self::new_with_backend(id_source, vertical, Some(value))
+		}
+	}
+
+	fn new_with_backend(id_source: impl std::hash::Hash, vertical: bool, custom_value_backend: Option<&'a mut T>) -> Self {
+		Self {
+			id: Id::new(id_source),
+
+			custom_value_backend,
+			tabs: Default::default(),
+			values: Default::default(),
+			values_are_indices: true,
+			default: Default::default(),
+
+			style: None,
+			style_edits: Vec::new(),
+			closable: false,
+			reorderable: false,
+			detachable: false,
+			add_button: false,
+			renamable: false,
+			context_menu: false,
+			context_menu_entries: None,
+			sizing: Default::default(),
+			overflow_menu: false,
+			wrap: false,
+			selection_animation: (0., Easing::EaseOut),
+			content_transition: (ConnectedTabsTransition::None, 0.2),
+			transition_fill: None,
+			retain_scroll: false,
+			#[cfg(feature = "serde")]
+			persist: false,
+
+			vertical,
+		}
+	}
+
+	crate::builder_set!{/// Sets the default tab, will have no effect if a custom index backend is set.
+		default_tab => default: usize}
+
+	#[cfg(feature = "serde")]
+	crate::builder_set!{/// Stores the current tab in persisted ui memory instead of tmp ui memory, so it is restored after a restart if eframe's `persistence` feature is enabled.
+		/// Will have no effect if a custom index backend is set (default: false)
+		persist: bool}
+
+	crate::builder_set!{/// Sets the style of this instance, if not set, the global style is used, see [ConnectedTabsStyle::set_global].
+		///
+		/// Functions that change parts of the style, such as `rounding_override`, are applied on top of either.
+		style: ConnectedTabsStyle => Some(style)}
+
+	/// Changes part of the style, whichever style ends up being used.
+	fn edit_style(mut self, edit: impl FnOnce(&mut ConnectedTabsStyle) + 'static) -> Self {
+		self.style_edits.push(Box::new(edit));
+		self
+	}
+
+	/// See [ConnectedTabsStyle::look].
+	pub fn look(self, look: ConnectedTabsLook) -> Self {
+		self.edit_style(move |style| style.look = look)
+	}
+	pub fn rounding_override(self, rounding: impl Into<Rounding>) -> Self {
+		let rounding = rounding.into();
+		self.edit_style(move |style| style.rounding = Some(rounding))
+	}
+	/// The bg fill is the color of the rectangle behind tab buttons.
+	pub fn bg_fill_override(self, bg_fill: Color32) -> Self {
+		self.edit_style(move |style| style.bg_fill = Some(bg_fill))
+	}
+	/// The volume of the buttons (default: [20.0, 15.0])
+	pub fn outer_button_margin(self, margin: impl Into<Vec2>) -> Self {
+		let margin = margin.into();
+		self.edit_style(move |style| style.outer_button_margin = margin)
+	}
+	/// The margin in-between buttons (default: 3.0)
+	pub fn inner_button_margin(self, margin: f32) -> Self {
+		self.edit_style(move |style| style.inner_button_margin = margin)
+	}
+	/// How much bigger or smaller the bg element is compared to the buttons (default: 1.5)
+	pub fn bg_margin(self, margin: f32) -> Self {
+		self.edit_style(move |style| style.bg_margin = margin)
+	}
+
+	/// Draws a line under the currently selected tab, width is a range from 0-1, 1 being 100% of the tab's width.
+	pub fn selected_line(self, stroke: Stroke, width: f32) -> Self {
+		self.edit_style(move |style| style.selected_line = (stroke, width))
+	}
+
+	pub fn selected_line_y_offset(self, offset: f32) -> Self {
+		self.edit_style(move |style| style.selected_line_y_offset = offset)
+	}
+
+	/// Makes the selected line and pill slide over to a newly selected tab over `time` seconds, instead of jumping to it (default: 0.0, EaseOut)
+	pub fn selection_animation(mut self, time: f32, easing: Easing) -> Self {
+		self.selection_animation = (time, easing);
+		self
+	}
+
+	/// Paints the selected tab's background as a pill which slides between tabs along with the selected line, see `selection_animation`.
+	/// Same as setting the look to [ConnectedTabsLook::Pill], or back to [ConnectedTabsLook::Segmented] if false.
+	pub fn selected_pill(self, selected_pill: bool) -> Self {
+		self.look(if selected_pill { ConnectedTabsLook::Pill } else { ConnectedTabsLook::Segmented })
+	}
+
+	/// Animates the contents shown by `show` over `time` seconds when switching tabs, see [ConnectedTabsTransition] (default: None, 0.2)
+	///
+	/// While transitioning, `add_contents` is called for both the old and the new tab.
+	pub fn content_transition(mut self, transition: ConnectedTabsTransition, time: f32) -> Self {
+		self.content_transition = (transition, time);
+		self
+	}
+
+	crate::builder_set!{/// The color behind the contents, egui can't fade a whole [Ui], so the cross-fade transition paints this over the contents to fade them.
+		/// Set this if the contents are in something with its own fill, such as a [Frame] or [Window] (default: the panel fill, or the frame fill with the browser look)
+		transition_fill: Color32 => Some(transition_fill)}
+
+	crate::builder_set!{/// Places the contents of each tab shown by `show_lazy` in its own vertical [ScrollArea], so switching tabs doesn't reset scrolling (default: false)
+		retain_scroll: bool}
+
+	crate::builder_set!{/// Adds a close button to every tab, pressing it (or middle-clicking the tab) reports the tab in [ConnectedTabsOutput::closed] (default: false)
+		closable: bool}
+	crate::builder_set!{/// Adds a "+" button after the last tab, pressing it reports [ConnectedTabsOutput::added] (default: false)
+		add_button: bool}
+	crate::builder_set!{/// Allows tabs to be renamed by double-clicking them, see [ConnectedTabsOutput::renamed] (default: false)
+		renamable: bool}
+	crate::builder_set!{/// Shows a menu when right-clicking a tab, with entries to close tabs (if `closable`) and duplicate them (except with `show_vec_mut`),
+		/// see [ConnectedTabsOutput::menu_action] (default: false)
+		context_menu: bool}
+
+	/// Adds entries of your own to the end of each tab's context menu, the index of the right-clicked tab is supplied.
+	/// Works even if `context_menu` is not enabled, in which case only your entries are shown.
+	pub fn context_menu_entries(mut self, add_contents: impl FnMut(&mut Ui, usize) + 'a) -> Self {
+		self.context_menu_entries = Some(Box::new(add_contents));
+		self
+	}
+
+	crate::builder_set!{/// How the length of each tab is decided, see [ConnectedTabsSizing] (default: Uniform)
+		sizing: ConnectedTabsSizing}
+	crate::builder_set!{/// When there are more tabs than fit, they can always be scrolled through, this adds a button that lists the hidden tabs (default: false)
+		overflow_menu: bool}
+	crate::builder_set!{/// When there are more tabs than fit, they wrap onto more rows (or columns, if vertical) instead of being scrolled through (default: false)
+		wrap: bool}
+	crate::builder_set!{/// Allows tabs to be dragged along the strip to reorder them, see [ConnectedTabsOutput::moved] (default: false)
+		reorderable: bool}
+	crate::builder_set!{/// Allows tabs to be dragged away from the strip into their own [Window], which shows the same contents as `show` would.
+		/// Closing the window or dragging it back onto the strip puts the tab back. Has no effect when only showing the strip with `ui` (default: false)
+		detachable: bool}
+
+	// Some internal functions
+
+	/// The component of `vec` along the strip.
+	fn along(&self, vec: Vec2) -> f32 {
+		if self.vertical {vec.y} else {vec.x}
+	}
+
+	/// The component of `vec` across the strip.
+	fn across(&self, vec: Vec2) -> f32 {
+		if self.vertical {vec.x} else {vec.y}
+	}
+
+	/// Creates a vector from components along and across the strip.
+	fn along_across(&self, along: f32, across: f32) -> Vec2 {
+		if self.vertical {vec2(across, along)} else {vec2(along, across)}
+	}
+
+	fn get_index(&mut self, ui: &Ui) -> usize {
+		if let Some(value) = &self.custom_value_backend {self.values.iter().position(|v| v == &**value).unwrap_or(self.default)}
+		else if self.persisted() {ui.memory_mut(|m| m.data.get_persisted(self.id).unwrap_or(self.default))}
+		else {ui.memory_mut(|m| m.data.get_temp(self.id).unwrap_or(self.default))}
+	}
+
+	fn set_index(&mut self, ui: &Ui, index: usize) {
+		if let Some(value) = &mut self.custom_value_backend {
+			if let Some(new_value) = self.values.get(index) {**value = new_value.clone()}
+		}
+		else if self.persisted() {ui.memory_mut(|m| m.data.insert_persisted(self.id, index))}
+		else {ui.memory_mut(|m| m.data.insert_temp(self.id, index))}
+	}
+
+	fn persisted(&self) -> bool {
+		#[cfg(feature = "serde")] return self.persist;
+		#[cfg(not(feature = "serde"))] false
+	}
+
+	// TODO: This documentation kinda sucks, make it more clear
+
+	/// Displays this instance, then ui just after it, with the value of the selected tab supplied (its index, unless made with `from_values`).
+	pub fn show(mut self, ui: &mut Ui, mut add_contents: impl FnMut(&mut Ui, T)) -> ConnectedTabsOutput
+	{
+		let output = self.show_tabs(ui, true);
+		// The caller only closes or moves tabs afterwards, so until then, the selected tab is where it was
+		let index = self.unshifted_index(output.index, &output);
+		self.show_in_frame(ui, &output, |ui| self.show_contents(ui, index, |ui, i| if let Some(value) = self.values.get(i) { add_contents(ui, value.clone()) }));
+		self.show_detached(ui, output.response.rect, |ui, i| if let Some(value) = self.values.get(i) { add_contents(ui, value.clone()) });
+		self.follow_shifts(ui, &output);
+
+		output
+	}
+
+	/// Shows the contents of detached tabs in their own windows, closing a window or dragging it back onto the strip (at `strip_rect`) re-docks its tab.
+	fn show_detached(&self, ui: &mut Ui, strip_rect: Rect, mut add_contents: impl FnMut(&mut Ui, usize))
+	{
+		let detached_id = self.id.with("detached");
+		let mut detached: Vec<usize> = ui.memory(|m| m.data.get_temp(detached_id)).unwrap_or_default();
+		if detached.is_empty() { return }
+
+		// Windows of tabs that were just detached open where they were dropped
+		let detached_at_id = self.id.with("detached_at");
+		let detached_at: Option<(usize, Pos2)> = ui.memory_mut(|m| {
+			let detached_at = m.data.get_temp(detached_at_id);
+			m.data.remove::<(usize, Pos2)>(detached_at_id);
+			detached_at
+		});
+
+		detached.retain(|&i|
+		{
+			let Some(tab) = self.tabs.get(i) else { return false };
+			let mut open = true;
+
+			// Windows are told apart the same way as tabs, so they keep their position and size when the tabs shift around, see `Tab::id_source`
+			let mut window = Window::new(tab.text.clone()).id(self.id.with(("detached_window", self.tab_id(i)))).open(&mut open);
+			if let Some((_, pos)) = detached_at.filter(|(detached_index, _)| *detached_index == i) {
+				window = window.current_pos(pos);
+			}
+			let response = window.show(ui.ctx(), |ui| add_contents(ui, i));
+
+			let dropped_on_strip = response.is_some_and(|response| response.response.drag_released())
+				&& ui.input(|input| input.pointer.interact_pos()).is_some_and(|pointer| strip_rect.contains(pointer));
+			open && !dropped_on_strip
+		});
+
+		ui.memory_mut(|m| m.data.insert_temp(detached_id, detached));
+	}
+
+	/// Like `show`, but the contents of each tab have their own [Id] scope, so widgets (such as scroll areas) in different tabs keep separate state,
+	/// and are told when they are first shown and when they are hidden, see [TabLifecycle].
+	///
+	/// Tabs are told apart by their index, unless given an id with [Tab::id_source]. Without one, the state of a tab's contents
+	/// stays at its index when tabs are closed or moved, instead of following the tab.
+	pub fn show_lazy(mut self, ui: &mut Ui, mut add_contents: impl FnMut(&mut Ui, T, TabLifecycle)) -> ConnectedTabsOutput
+	{
+		let output = self.show_tabs(ui, true);
+		let index = self.unshifted_index(output.index, &output);
+
+		// Stores the indices of the tabs shown before, and the ones drawn last frame
+		let lifecycle_id = self.id.with("lifecycle");
+		let (mut shown, last_drawn): (Vec<usize>, Vec<usize>) = ui.memory(|m| m.data.get_temp(lifecycle_id)).unwrap_or_default();
+
+		// The tab switched away from is still drawn until the transition away from it finishes
+		let mut drawn = vec![index];
+		if let Some((previous, _)) = self.transition_progress(ui, index) { drawn.push(previous) }
+
+		// The contents of each tab are in their own id scope, optionally with their own scroll area
+		let retain_scroll = self.retain_scroll;
+		let mut add_tab_contents = |ui: &mut Ui, i: usize, lifecycle: TabLifecycle| {
+			let Some(value) = self.values.get(i) else { return };
+			ui.push_id(("tab_contents", self.tab_id(i)), |ui| {
+				if retain_scroll { ScrollArea::vertical().show(ui, |ui| add_contents(ui, value.clone(), lifecycle)); }
+				else { add_contents(ui, value.clone(), lifecycle) }
+			});
+		};
+
+		for hidden in last_drawn.into_iter().filter(|i| !drawn.contains(i)) {
+			let mut hidden_ui = ui.child_ui(ui.available_rect_before_wrap(), *ui.layout());
+			hidden_ui.set_visible(false);
+			add_tab_contents(&mut hidden_ui, hidden, TabLifecycle::Hidden);
+		}
+
+		// The indices are stored for the tabs as they will be once the caller closes or moves any
+		let first_shown = !shown.contains(&index);
+		if first_shown { shown.push(index) }
+		let shift = |indices: Vec<usize>| indices.into_iter().filter_map(|i| Self::shifted_index(i, &output)).collect::<Vec<_>>();
+		ui.memory_mut(|m| m.data.insert_temp(lifecycle_id, (shift(shown), shift(drawn))));
+
+		self.show_in_frame(ui, &output, |ui| self.show_contents(ui, index, |ui, i| {
+			add_tab_contents(ui, i, if i == index && first_shown { TabLifecycle::FirstShown } else { TabLifecycle::Shown })
+		}));
+		self.show_detached(ui, output.response.rect, |ui, i| add_tab_contents(ui, i, TabLifecycle::Shown));
+		self.follow_shifts(ui, &output);
+
+		output
+	}
+
+	/// Where the tab at `index` ends up once the tabs closed, moved, or duplicated this frame are, if it still exists.
+	fn shifted_index(index: usize, output: &ConnectedTabsOutput) -> Option<usize>
+	{
+		let mut index = Some(index);
+		if let Some(closed) = output.closed {
+			index = index.filter(|&i| i != closed).map(|i| if i > closed { i - 1 } else { i });
+		}
+		if let Some((from, to)) = output.moved {
+			index = index.map(|i| if i == from { to } else if from < i && i <= to { i - 1 } else if to <= i && i < from { i + 1 } else { i });
+		}
+		match output.menu_action {
+			Some((target, ConnectedTabsMenuAction::CloseOthers)) => index.filter(|&i| i == target).map(|_| 0),
+			Some((target, ConnectedTabsMenuAction::CloseToTheRight)) => index.filter(|&i| i <= target),
+			Some((target, ConnectedTabsMenuAction::Duplicate)) => index.map(|i| if i > target { i + 1 } else { i }),
+			None => index,
+		}
+	}
+
+	/// What tells the tab at `index` apart from the others, see [Tab::id_source].
+	fn tab_id(&self, index: usize) -> Id {
+		self.tabs.get(index).and_then(|tab| tab.id).unwrap_or_else(|| Id::new(index))
+	}
+
+	/// Where the tab that ends up at `index` was before the tabs closed, moved, or duplicated this frame were, see `shifted_index`.
+	fn unshifted_index(&self, index: usize, output: &ConnectedTabsOutput) -> usize {
+		(0..self.tabs.len()).find(|&i| Self::shifted_index(i, output) == Some(index)).unwrap_or(index)
+	}
+
+	/// Moves state kept by index, such as the tabs a transition is between, along with the tabs closed, moved, or duplicated this frame,
+	/// call once the contents of the tabs as they were have been shown.
+	fn follow_shifts(&self, ui: &Ui, output: &ConnectedTabsOutput)
+	{
+		if output.closed.is_none() && output.moved.is_none() && output.menu_action.is_none() { return }
+
+		// Tabs shifting over isn't a change of contents, so a transition keeps going between the same tabs, or stops if they're gone
+		let transition_id = self.id.with("content_transition");
+		if let Some((from, to, count)) = ui.memory(|m| m.data.get_temp::<(usize, usize, u32)>(transition_id)) {
+			let to = Self::shifted_index(to, output).unwrap_or(output.index);
+			let from = Self::shifted_index(from, output).unwrap_or(to);
+			ui.memory_mut(|m| m.data.insert_temp(transition_id, (from, to, count)));
+		}
+
+		// Detached tabs follow along too, their windows are closed along with them
+		ui.memory_mut(|m| {
+			let detached = m.data.get_temp_mut_or_default::<Vec<usize>>(self.id.with("detached"));
+			*detached = detached.iter().filter_map(|&i| Self::shifted_index(i, output)).collect();
+		});
+	}
+
+	/// With the browser look, contents are shown in a frame attached to the tabs, otherwise they're shown as is.
+	fn show_in_frame(&self, ui: &mut Ui, output: &ConnectedTabsOutput, add_contents: impl FnOnce(&mut Ui))
+	{
+		let Some(style) = self.style.as_ref().filter(|style| style.look == ConnectedTabsLook::Browser) else { return add_contents(ui) };
+		let (fill, stroke) = Self::browser_colors(ui, style);
+
+		// The frame goes right up against the tabs
+		ui.add_space(-self.across(ui.spacing().item_spacing));
+		Frame::none().fill(fill).stroke(stroke).inner_margin(ui.spacing().window_margin).show(ui, add_contents);
+
+		// The selected tab opens up into the frame
+		if let Some(tab_rect) = output.tab_response(output.index).map(|response| response.rect) {
+			let (start, end, edge) = (self.along(tab_rect.min.to_vec2()) + stroke.width, self.along(tab_rect.max.to_vec2()) - stroke.width, self.across(tab_rect.max.to_vec2()));
+			ui.painter().line_segment([self.along_across(start, edge).to_pos2(), self.along_across(end, edge).to_pos2()], Stroke::new(stroke.width, fill));
+		}
+	}
+
+	/// The fill of the selected tab and the contents frame, and the outline of tabs and the frame, for the browser look.
+	fn browser_colors(ui: &Ui, style: &ConnectedTabsStyle) -> (Color32, Stroke) {
+		(style.bg_fill.unwrap_or(ui.visuals().panel_fill), ui.visuals().widgets.noninteractive.bg_stroke)
+	}
+
+	/// The style this instance uses, the global one if none was set, with the changes made by builder functions applied.
+	fn resolve_style(&mut self, ctx: &Context) -> ConnectedTabsStyle
+	{
+		let mut style = self.style.take().unwrap_or_else(|| ConnectedTabsStyle::global(ctx));
+		for edit in self.style_edits.drain(..) {
+			edit(&mut style);
+		}
+
+		self.style = Some(style.clone());
+		style
+	}
+
+	/// Shows the contents of the tab at `index`, transitioning over from the previously shown tab if enabled.
+	fn show_contents(&self, ui: &mut Ui, index: usize, mut add_contents: impl FnMut(&mut Ui, usize))
+	{
+		let Some((previous, progress)) = self.transition_progress(ui, index) else { return add_contents(ui, index) };
+		let progress = Easing::EaseInOut.apply(progress);
+
+		let max_rect = ui.available_rect_before_wrap();
+		let layout = *ui.layout();
+
+		// The previous contents get their own id, so their widgets don't clash with the new ones, and can't be interacted with on their way out
+		let mut add_at = |ui: &mut Ui, i: usize, offset: Vec2, clip_rect: Rect| {
+			let mut child = if i == index { ui.child_ui(max_rect.translate(offset), layout) }
+				else { ui.child_ui_with_id_source(max_rect.translate(offset), layout, (self.id, "previous_contents")) };
+			child.set_clip_rect(clip_rect);
+			if i != index { child.set_enabled(false) }
+			add_contents(&mut child, i);
+			child.min_rect().translate(-offset)
+		};
+
+		let contents_rect = match self.content_transition.0
+		{
+			ConnectedTabsTransition::Slide => {
+				// Switching to a later tab, the contents move towards the start of the strip, like turning a page
+				let distance = self.along(max_rect.size()) * if index > previous { 1. } else { -1. };
+				let clip_rect = max_rect.intersect(ui.clip_rect());
+
+				add_at(ui, previous, self.along_across(-distance * progress, 0.), clip_rect)
+					.union(add_at(ui, index, self.along_across(distance * (1. - progress), 0.), clip_rect))
+			}
+			_ => {
+				// Egui can't fade a whole `Ui`, so contents are faded out by painting the background color over them,
+				// which is the fill of the frame they're in with the browser look.
+				// The fainter contents are painted first, then the second overlay fades both of them,
+				// so the first overlay only needs to make up the difference
+				let (fainter, stronger) = if progress < 0.5 { ((index, progress), (previous, 1. - progress)) } else { ((previous, 1. - progress), (index, progress)) };
+				let fill = self.transition_fill.unwrap_or_else(|| match self.style.as_ref().filter(|style| style.look == ConnectedTabsLook::Browser) {
+					Some(style) => Self::browser_colors(ui, style).0,
+					None => ui.visuals().panel_fill,
+				});
+				let clip_rect = ui.clip_rect();
+
+				let fainter_rect = add_at(ui, fainter.0, Vec2::ZERO, clip_rect);
+				ui.painter().rect_filled(fainter_rect, 0., fill.gamma_multiply(1. - fainter.1 / stronger.1));
+				let contents_rect = fainter_rect.union(add_at(ui, stronger.0, Vec2::ZERO, clip_rect));
+				ui.painter().rect_filled(contents_rect, 0., fill.gamma_multiply(1. - stronger.1));
+
+				contents_rect
+			}
+		};
+
+		ui.allocate_rect(contents_rect, Sense::hover());
+	}
+
+	/// If the shown contents are transitioning between tabs, this is the previously shown tab, and how far along the transition is from 0-1.
+	fn transition_progress(&self, ui: &Ui, index: usize) -> Option<(usize, f32)>
+	{
+		if self.content_transition.0 == ConnectedTabsTransition::None { return None }
+
+		// Stores `(from, to, count)`, the count goes up by one with every transition, and is what's animated
+		let state_id = self.id.with("content_transition");
+		let (mut from, mut to, mut count): (usize, usize, u32) = ui.memory(|m| m.data.get_temp(state_id)).unwrap_or((index, index, 0));
+		if to != index {
+			(from, to, count) = (to, index, count.wrapping_add(1));
+		}
+		ui.memory_mut(|m| m.data.insert_temp(state_id, (from, to, count)));
+
+		let animated = ui.ctx().animate_value_with_time(state_id, count as f32, self.content_transition.1);
+		let progress = 1. - (count as f32 - animated).clamp(0., 1.);
+		(progress < 1. && from != index).then_some((from, progress))
+	}
+
+	/// Shows the tab strip itself, without any contents.
+	/// The selection follows the tabs closed, moved, or duplicated, see `shifted_index`. The context menu only offers duplicating tabs if `duplicates` is true.
+	fn show_tabs(&mut self, ui: &mut Ui, duplicates: bool) -> ConnectedTabsOutput
+	{
+		let style = self.resolve_style(ui.ctx());
+
+		// Detached tabs are shown in their own windows instead of in the strip
+		let detached_id = self.id.with("detached");
+		for i in ui.memory(|m| m.data.get_temp::<Vec<usize>>(detached_id)).unwrap_or_default() {
+			if let Some(tab) = self.tabs.get_mut(i) { tab.visible = false }
+		}
+
+		let rounding = style.rounding.unwrap_or(ui.visuals().widgets.noninteractive.rounding);
+		// Only the segmented and pill looks have a bg element, the browser look uses the bg fill for the selected tab instead
+		let bg_color = match style.look {
+			ConnectedTabsLook::Segmented | ConnectedTabsLook::Pill => style.bg_fill.unwrap_or(ui.visuals().widgets.inactive.bg_fill),
+			ConnectedTabsLook::Underline | ConnectedTabsLook::Browser => Color32::TRANSPARENT,
+		};
+		// The underline look always has a line under the selected tab, even if none is set
+		let selected_line = if style.look == ConnectedTabsLook::Underline && style.selected_line.1 <= 0. { (Stroke::new(2., ui.visuals().selection.bg_fill), 1.) }
+			else { style.selected_line };
+
+		// Space taken up by the close button (and the gap before it) inside each tab
+		let close_button_width = if self.closable { ui.spacing().icon_width + ui.spacing().icon_spacing } else { 0. };
+
+		// Measure every tab, including its margin and close button, invisible tabs don't take up any space
+		let content_sizes: Vec<Vec2> = self.tabs.iter()
+			.map(|tab| if !tab.visible { Vec2::ZERO } else {
+				tab.layout(ui).size() + style.outer_button_margin + vec2(close_button_width, 0.)
+			})
+			.collect();
+
+		// How long each tab is along the strip, they all share the same size across it
+		let lengths: Vec<f32> = match self.sizing {
+			ConnectedTabsSizing::Uniform => {
+				let longest = content_sizes.iter().map(|size| self.along(*size)).fold(0., f32::max);
+				self.tabs.iter().map(|tab| if tab.visible { longest } else { 0. }).collect()
+			}
+			ConnectedTabsSizing::FitContent | ConnectedTabsSizing::Fill => content_sizes.iter().map(|size| self.along(*size)).collect(),
+		};
+		let thickness = content_sizes.iter().map(|size| self.across(*size)).fold(0., f32::max);
+		let visible_count = self.tabs.iter().filter(|tab| tab.visible).count();
+		let margins = style.inner_button_margin * visible_count.saturating_sub(1) as f32;
+
+		// The add button always sits at the end of the strip, the overflow buttons are the same size
+		let overflow_button_length = ui.spacing().icon_width + style.inner_button_margin * 2.;
+		let add_button_length = if self.add_button { overflow_button_length } else { 0. };
+
+		// Calculate total size of this widget then allocate it
+		let available_length = self.along(ui.available_size_before_wrap()) - add_button_length;
+		let mut tabs_length = lengths.iter().sum::<f32>() + margins;
+		let wrapping = self.wrap && tabs_length > available_length;
+
+		// When wrapping, tabs that don't fit on a line go on the next one, this is the line of each tab
+		let mut lines = Vec::with_capacity(self.tabs.len());
+		// The total length of the tabs on each line, and how many there are
+		let mut line_contents = vec![(0., 0)];
+		for (&length, tab) in lengths.iter().zip(&self.tabs)
+		{
+			let (line_length, line_count) = line_contents.last_mut().unwrap();
+			// The margins before each tab on the line, including the one before this tab
+			let line_length_with_margins = *line_length + style.inner_button_margin * *line_count as f32;
+			if wrapping && tab.visible && *line_count > 0 && line_length_with_margins + length > available_length {
+				line_contents.push((0., 0));
+			}
+
+			let (line_length, line_count) = line_contents.last_mut().unwrap();
+			if tab.visible { (*line_length, *line_count) = (*line_length + length, *line_count + 1) }
+			lines.push(line_contents.len() - 1);
+		}
+		let line_margins = |count: usize| style.inner_button_margin * count.saturating_sub(1) as f32;
+		if wrapping {
+			tabs_length = line_contents.iter().map(|&(length, count)| length + line_margins(count)).fold(0., f32::max);
+		}
+
+		if self.sizing == ConnectedTabsSizing::Fill && available_length.is_finite() {
+			tabs_length = tabs_length.max(available_length);
+		}
+
+		// If the tabs don't fit, only as much as is available is allocated, and the tabs can be scrolled through instead
+		let overflowing = !wrapping && tabs_length > available_length;
+		let strip_thickness = thickness * line_contents.len() as f32 + line_margins(line_contents.len());
+		let (rect, _) = ui.allocate_exact_size(self.along_across(if overflowing { available_length } else { tabs_length } + add_button_length, strip_thickness), Sense::hover());
+		// Where the tabs and overflow buttons go, everything but the add button
+		let strip_rect = Rect::from_min_max(rect.min, rect.max - self.along_across(add_button_length, 0.));
+
+		// The strip itself is what receives keyboard focus, but the tabs and buttons in it have to be focusable to be clicked.
+		// So focus stays locked on the strip while they're added, and only afterwards is it passed on by Tab, skipping over them,
+		// Shift+Tab passes it back right away. Ctrl+Tab would normally move focus to the next widget too, so it's kept here instead
+		let ctrl_tab = ui.input(|input| input.modifiers.ctrl && input.key_pressed(Key::Tab));
+		let shift_tab = !ctrl_tab && ui.input(|input| input.modifiers.shift && input.key_pressed(Key::Tab));
+		if !shift_tab { ui.memory_mut(|m| m.lock_focus(self.id, true)) }
+		let response = ui.interact(rect, self.id, Sense::click());
+		let focused = response.has_focus();
+
+		// While overflowing, the ends of the strip are taken up by the scroll buttons, and optionally the overflow menu button
+		let viewport = if overflowing {
+			let overflow_button_count = if self.overflow_menu { 2. } else { 1. };
+			Rect::from_min_max(strip_rect.min + self.along_across(overflow_button_length, 0.), strip_rect.max - self.along_across(overflow_button_length * overflow_button_count, 0.))
+		} else { strip_rect };
+
+		// Spread the tabs of each line over the allocated rect proportionally to their lengths,
+		// `ui.allocate_exact_size` does not always allocate the exact size i want, and fill mode wants to grow the tabs anyway.
+		// Wrapped lines are only grown in fill mode, otherwise every line would be stretched to the longest one
+		let line_scales: Vec<f32> = line_contents.iter().map(|&(length, count)| {
+			if overflowing || length <= 0. || (wrapping && self.sizing != ConnectedTabsSizing::Fill) { 1. }
+			else { (self.along(strip_rect.size()) - line_margins(count)) / length }
+		}).collect();
+
+		// How far the tabs are scrolled along the strip
+		let scroll_id = self.id.with("scroll");
+		let max_scroll = if overflowing { (tabs_length - self.along(viewport.size())).max(0.) } else { 0. };
+		let mut scroll: f32 = ui.memory_mut(|m| m.data.get_temp(scroll_id).unwrap_or_default());
+
+		// Scrolling the mouse wheel over the strip scrolls through the tabs, either wheel axis works for horizontal strips
+		if overflowing && ui.rect_contains_pointer(viewport)
+		{
+			let scroll_delta = ui.input(|input| input.scroll_delta);
+			let scroll_delta = if self.vertical { scroll_delta.y } else { scroll_delta.x + scroll_delta.y };
+
+			if scroll_delta != 0. {
+				scroll -= scroll_delta;
+				// Stop scroll areas containing this strip from scrolling too
+				ui.input_mut(|input| input.scroll_delta = Vec2::ZERO);
+			}
+		}
+		scroll = scroll.clamp(0., max_scroll);
+
+		let line_thickness = (self.across(rect.size()) - line_margins(line_contents.len())) / line_contents.len() as f32;
+		let mut tab_pos = viewport.min - self.along_across(scroll, 0.);
+		let mut current_line = 0;
+		let tab_rects: Vec<Rect> = lengths.iter().zip(&self.tabs).zip(&lines).map(|((length, tab), &line)| {
+			if line != current_line {
+				current_line = line;
+				tab_pos = viewport.min + self.along_across(0., (line_thickness + style.inner_button_margin) * line as f32);
+			}
+
+			let tab_rect = Rect::from_min_size(tab_pos, self.along_across(length * line_scales[line], line_thickness));
+			if tab.visible { tab_pos += self.along_across(length * line_scales[line] + style.inner_button_margin, 0.) }
+			tab_rect
+		}).collect();
+		// Where all of the tabs lie, even if they're scrolled out of view
+		let content_rect = tab_rects.iter().zip(&self.tabs).filter(|(_, tab)| tab.visible).map(|(tab_rect, _)| *tab_rect)
+			.reduce(Rect::union).unwrap_or(viewport);
+		
+		// Paint bg element
+		if bg_color.a() > 0 {
+			ui.painter().rect_filled(
+				rect.expand(style.bg_margin),
+				rounding,
+				bg_color
+			);
+		}
+
+		// Buttons
+		let previous_index = self.get_index(ui);
+		let mut current_index = previous_index.min(self.tabs.len().saturating_sub(1)); // Stores the current index to be changed
+
+		// Disabled or invisible tabs can't stay selected, the closest selectable tab after it is used instead, or before it if there are none
+		if self.tabs.get(current_index).is_some_and(|tab| !tab.selectable()) {
+			current_index = self.next_selectable(current_index, 1, false).or_else(|| self.next_selectable(current_index, -1, false)).unwrap_or(current_index);
+		}
+		response.widget_info(|| self.tab_info(current_index, true));
+		let mut closed = None;
+		let mut moved = None;
+		let mut renamed = None;
+		let mut menu_action = None;
+		let mut tab_responses = vec![None; self.tabs.len()];
+		let mut context_menu_entries = self.context_menu_entries.take();
+
+		// While a tab is being renamed, this stores its index and the name typed so far
+		let rename_id = self.id.with("rename");
+		let rename_edit_id = rename_id.with("text_edit");
+
+		// The slot under the pointer, this is where a dragged tab will be dropped,
+		// when wrapping, every tab on an earlier line than the pointer comes before it too
+		let slot_under_pointer = |ui: &Ui| {
+			let pointer = ui.input(|input| input.pointer.interact_pos()).unwrap_or(rect.min).to_vec2();
+			tab_rects.iter().zip(&self.tabs)
+				.rposition(|(tab_rect, tab)| tab.visible && (
+					(wrapping && self.across(tab_rect.max.to_vec2()) < self.across(pointer)) ||
+					(self.along(tab_rect.min.to_vec2()) <= self.along(pointer) && (!wrapping || self.across(tab_rect.min.to_vec2()) <= self.across(pointer)))
+				))
+				.or_else(|| self.tabs.iter().position(|tab| tab.visible))
+				.unwrap_or(0)
+		};
+
+		// If a tab is being dragged, this is `(from, to)`
+		// Dragging a tab far enough away from the strip detaches it
+		let detach_zone = rect.expand(self.across(rect.size()));
+		let detaching = |ui: &Ui| self.detachable && ui.input(|input| input.pointer.interact_pos()).is_some_and(|pointer| !detach_zone.contains(pointer));
+
+		// If a tab is being dragged, this is `(from, to)`, tabs that are about to be detached stay where they are
+		let drag = if !self.reorderable && !self.detachable { None } else {
+			(0..self.tabs.len()).find(|&i| ui.memory(|m| m.is_being_dragged(self.id.with(i))))
+				.map(|from| (from, if self.reorderable && !detaching(ui) { slot_under_pointer(ui) } else { from }))
+		};
+
+		// Animations of tabs sliding out of the way are keyed on this, so they start fresh every drag,
+		// otherwise the tabs would slide back from their old positions after being moved
+		let drag_generation_id = self.id.with("drag_generation");
+		let drag_generation: u32 = ui.memory_mut(|m| m.data.get_temp(drag_generation_id).unwrap_or_default());
+
+		// Show where the dragged tab will be dropped, or where its window will open
+		if let (Some((from, _)), Some(pointer)) = (drag.filter(|_| detaching(ui)), ui.input(|input| input.pointer.interact_pos())) {
+			ui.ctx().layer_painter(LayerId::new(Order::Tooltip, self.id.with("detach_preview")))
+				.rect_stroke(Rect::from_min_size(pointer, tab_rects[from].size()), rounding, Stroke::new(1., ui.visuals().selection.bg_fill));
+		}
+		else if let Some((from, to)) = drag.filter(|_| self.reorderable)
+		{
+			// Moving forwards, the dragged tab ends where the target ends, moving backwards, it starts where the target starts
+			let size = tab_rects[from].size();
+			let slot = if to > from { Rect::from_min_size(tab_rects[to].max - size, size) } else { Rect::from_min_size(tab_rects[to].min, size) };
+
+			ui.painter_at(viewport).rect_stroke(slot, rounding, Stroke::new(1., ui.visuals().selection.bg_fill));
+		}
+
+		// While being dragged, a tab follows the pointer along the strip,
+		// and the tabs it passes over slide out of the way to make room for it
+		let button_rects: Vec<Rect> = tab_rects.iter().enumerate().map(|(i, tab_rect)| {
+			let offset = match drag
+			{
+				Some((from, _)) if from == i => {
+					let drag_delta = ui.input(|input| Some(input.pointer.interact_pos()? - input.pointer.press_origin()?)).unwrap_or_default();
+					self.along(drag_delta).clamp(self.along(content_rect.min - tab_rect.min), self.along(content_rect.max - tab_rect.max))
+				}
+				Some((from, to)) => {
+					let stride = self.along(tab_rects[from].size()) + style.inner_button_margin;
+					let shift = if from < i && i <= to { -stride } else if to <= i && i < from { stride } else { 0. };
+					ui.ctx().animate_value_with_time(self.id.with((i, "reorder_shift", drag_generation)), shift, ui.style().animation_time)
+				}
+				None => 0.,
+			};
+
+			tab_rect.translate(self.along_across(offset, 0.))
+		}).collect();
+
+		// The selected tab's pill sits behind all of the tabs
+		let selection_rect = self.selection_rect(ui, &button_rects, current_index);
+		if let Some(selection_rect) = selection_rect.filter(|_| style.look == ConnectedTabsLook::Pill) {
+			let pill_rounding = style.rounding.unwrap_or(ui.visuals().widgets.inactive.rounding);
+			ui.painter_at(viewport).rect_filled(selection_rect, pill_rounding, ui.visuals().selection.bg_fill);
+		}
+
+
+		//////////////////////////////////////////////////////////////////////////////////
+		//// BUTTONS
+		//////////////////////////////////////////////////////////////////////////////////
+
+		for (i, &button_rect) in button_rects.iter().enumerate()
+		{
+			if !self.tabs[i].visible { continue }
+
+			ui.scope(|ui|
+			{
+				ui.set_enabled(self.tabs[i].enabled);
+
+				// Rounding override application
+				if let Some(rounding) = style.rounding {
+					ui.visuals_mut().widgets.set_all_rounding(rounding);
+				}
+
+				// Tabs scrolled out of view are hidden
+				ui.set_clip_rect(viewport.intersect(ui.clip_rect()));
+
+				let tab_id = self.id.with(i);
+				let dragged = drag.is_some_and(|(from, _)| from == i);
+
+				let add_tab = |ui: &mut Ui| {
+					// Display button
+					let button = self.tab_button(ui, i, button_rect, i == current_index, close_button_width, &style);
+
+					// Display close button, on the right side of the tab
+					let close_clicked = self.closable && {
+						let close_rect = Rect::from_center_size(
+							pos2(button_rect.right() - (style.outer_button_margin.x + ui.spacing().icon_width) / 2., button_rect.center().y),
+							Vec2::splat(ui.spacing().icon_width),
+						);
+						Self::close_button(ui, self.id.with(("close", i)), close_rect).clicked() || button.middle_clicked()
+					};
+
+					(button, close_clicked)
+				};
+
+				// Dragged tabs are painted above the rest of the strip, on a layer that still allows interaction so the drag can be released
+				let (button, mut close_clicked) = if dragged { ui.with_layer_id(LayerId::new(Order::Foreground, tab_id), add_tab).inner }
+					else { add_tab(ui) };
+
+				// Right-clicking the tab opens its context menu
+				let button = if !self.context_menu && context_menu_entries.is_none() { button } else {
+					button.context_menu(|ui|
+					{
+						if self.context_menu
+						{
+							let entry = |ui: &mut Ui, text: &str, enabled: bool| {
+								let clicked = ui.add_enabled(enabled, Button::new(text)).clicked();
+								if clicked { ui.close_menu() }
+								clicked
+							};
+							let (only_tab, last_tab) = (self.tabs.len() <= 1, i + 1 >= self.tabs.len());
+
+							if self.closable
+							{
+								if entry(ui, "Close", true) { close_clicked = true }
+								if entry(ui, "Close others", !only_tab) { menu_action = Some((i, ConnectedTabsMenuAction::CloseOthers)) }
+								if entry(ui, "Close to the right", !last_tab) { menu_action = Some((i, ConnectedTabsMenuAction::CloseToTheRight)) }
+								ui.separator();
+							}
+							if duplicates && entry(ui, "Duplicate", true) { menu_action = Some((i, ConnectedTabsMenuAction::Duplicate)) }
+						}
+
+						if let Some(add_contents) = &mut context_menu_entries
+						{
+							if self.context_menu { ui.separator(); }
+							add_contents(ui, i);
+						}
+					})
+				};
+
+				// Dropping the tab moves it to the slot under the pointer, or detaches it if it's far enough away
+				if (self.reorderable || self.detachable) && button.drag_released()
+				{
+					if detaching(ui) {
+						let pointer = ui.input(|input| input.pointer.interact_pos()).unwrap_or_default();
+						ui.memory_mut(|m| {
+							m.data.get_temp_mut_or_default::<Vec<usize>>(detached_id).push(i);
+							m.data.insert_temp(self.id.with("detached_at"), (i, pointer));
+						});
+					}
+					else if self.reorderable {
+						let to = slot_under_pointer(ui);
+						if to != i { moved = Some((i, to)) }
+					}
+
+					ui.memory_mut(|m| m.data.insert_temp(drag_generation_id, drag_generation.wrapping_add(1)));
+				}
+
+				// If the close button was clicked, report it, else if the tab was clicked, change the current index
+				if close_clicked {
+					closed = Some(i);
+				}
+				else if button.clicked() {
+					current_index = i;
+					ui.memory_mut(|m| m.request_focus(self.id));
+				}
+
+				// Double-clicking a tab starts renaming it, this has to come after the click takes focus
+				if self.renamable && button.double_clicked() {
+					ui.memory_mut(|m| {
+						m.data.insert_temp(rename_id, (i, self.tabs[i].text.text().to_owned()));
+						m.request_focus(rename_edit_id);
+					});
+				}
+
+				// The name is edited in place, pressing enter or clicking away commits it, pressing escape cancels it
+				if let Some((_, mut name)) = ui.memory(|m| m.data.get_temp::<(usize, String)>(rename_id)).filter(|(renaming, _)| *renaming == i)
+				{
+					let mut edit_ui = ui.child_ui(button_rect, Layout::centered_and_justified(Direction::LeftToRight));
+					let edit = edit_ui.add(TextEdit::singleline(&mut name).id(rename_edit_id));
+
+					if edit.has_focus() { ui.memory_mut(|m| m.data.insert_temp(rename_id, (i, name))) }
+					else {
+						if edit.lost_focus() && !ui.input(|input| input.key_pressed(Key::Escape)) { renamed = Some((i, name)) }
+						ui.memory_mut(|m| m.data.remove::<(usize, String)>(rename_id));
+					}
+				}
+
+				tab_responses[i] = Some(button);
+			});
+		}
+
+		// Draw selectable_line under the selected tab, if width is above 0
+		if let Some(selection_rect) = selection_rect.filter(|_| selected_line.1 > 0.)
+		{
+			let center = selection_rect.center().x;
+			let offset = (selection_rect.width() / 2.) * selected_line.1;
+			ui.painter_at(viewport.expand(style.bg_margin + style.selected_line_y_offset.abs() + selected_line.0.width))
+				.hline((center-offset)..=(center+offset), selection_rect.bottom() + style.selected_line_y_offset, selected_line.0);
+		}
+
+		// With the browser look, a line runs along the edge of the strip facing the contents, except where the selected tab opens up into them
+		if style.look == ConnectedTabsLook::Browser
+		{
+			let stroke = Self::browser_colors(ui, &style).1;
+			let (start, end, edge) = (self.along(rect.min.to_vec2()), self.along(rect.max.to_vec2()), self.across(rect.max.to_vec2()));
+			let (gap_start, gap_end) = selection_rect.map_or((end, end), |selection_rect| (self.along(selection_rect.min.to_vec2()), self.along(selection_rect.max.to_vec2())));
+
+			for (from, to) in [(start, gap_start.max(start)), (gap_end.min(end), end)] {
+				if to > from { ui.painter().line_segment([self.along_across(from, edge).to_pos2(), self.along_across(to, edge).to_pos2()], stroke) }
+			}
+		}
+
+		//////////////////////////////////////////////////////////////////////////////////
+		//// KEYBOARD
+		//////////////////////////////////////////////////////////////////////////////////
+
+		if focused && !self.tabs.is_empty()
+		{
+			let (previous_key, next_key) = if self.vertical { (Key::ArrowUp, Key::ArrowDown) } else { (Key::ArrowLeft, Key::ArrowRight) };
+			let (first, last) = (self.tabs.iter().position(Tab::selectable), self.tabs.iter().rposition(Tab::selectable));
+
+			// Tabs that can't be selected are skipped over
+			let new_index = ui.input_mut(|input|
+			{
+				if input.consume_key(Modifiers::NONE, previous_key) { self.next_selectable(current_index, -1, false) }
+				else if input.consume_key(Modifiers::NONE, next_key) { self.next_selectable(current_index, 1, false) }
+				else if input.consume_key(Modifiers::NONE, Key::Home) { first }
+				else if input.consume_key(Modifiers::NONE, Key::End) { last }
+				// Ctrl+Tab wraps around, like in most tabbed applications
+				else if input.consume_key(Modifiers::CTRL, Key::Tab) { self.next_selectable(current_index, 1, true) }
+				else if input.consume_key(Modifiers::CTRL | Modifiers::SHIFT, Key::Tab) { self.next_selectable(current_index, -1, true) }
+				else { None }
+			});
+			if let Some(new_index) = new_index { current_index = new_index }
+
+			// Focus ring around the selected tab
+			if let Some(tab_rect) = tab_rects.get(current_index) {
+				ui.painter_at(viewport.expand(style.bg_margin + 1.)).rect_stroke(tab_rect.expand(1.), rounding, ui.visuals().selection.stroke);
+			}
+		}
+
+
+		//////////////////////////////////////////////////////////////////////////////////
+		//// OVERFLOW
+		//////////////////////////////////////////////////////////////////////////////////
+
+		if overflowing
+		{
+			let button_size = self.along_across(overflow_button_length, self.across(rect.size()));
+			let (backward, forward) = (self.along_across(-1., 0.), self.along_across(1., 0.));
+
+			// The scroll buttons scroll just far enough to fully show the next hidden tab
+			let back_button = Self::overflow_button(ui, self.id.with("scroll_back"), Rect::from_min_size(rect.min, button_size), Some(backward));
+			if back_button.clicked() {
+				if let Some(tab_rect) = tab_rects.iter().rev().find(|tab_rect| self.along(tab_rect.min - viewport.min) < -0.5) {
+					scroll += self.along(tab_rect.min - viewport.min);
+				}
+			}
+
+			let forward_button = Self::overflow_button(ui, self.id.with("scroll_forward"), Rect::from_min_size(viewport.max - self.along_across(0., self.across(rect.size())), button_size), Some(forward));
+			if forward_button.clicked() {
+				if let Some(tab_rect) = tab_rects.iter().find(|tab_rect| self.along(tab_rect.max - viewport.max) > 0.5) {
+					scroll += self.along(tab_rect.max - viewport.max);
+				}
+			}
+
+			// The overflow menu lists every tab that isn't fully visible
+			if self.overflow_menu
+			{
+				let menu_button = Self::overflow_button(ui, self.id.with("overflow_menu"), Rect::from_min_size(strip_rect.max - button_size, button_size), None);
+				let popup_id = self.id.with("overflow_popup");
+				if menu_button.clicked() {
+					ui.memory_mut(|m| m.toggle_popup(popup_id));
+				}
+
+				popup_below_widget(ui, popup_id, &menu_button, |ui|
+				{
+					for (i, tab) in self.tabs.iter().enumerate()
+					{
+						let hidden = self.along(tab_rects[i].min - viewport.min) < -0.5 || self.along(tab_rects[i].max - viewport.max) > 0.5;
+						if tab.visible && hidden && ui.add_enabled(tab.enabled, SelectableLabel::new(i == current_index, tab.text.clone())).clicked() {
+							current_index = i;
+						}
+					}
+				});
+			}
+
+			// Whenever the selection changes, scroll the selected tab into view
+			let scrolled_to_id = self.id.with("scrolled_to");
+			if ui.memory(|m| m.data.get_temp(scrolled_to_id)) != Some(current_index)
+			{
+				if let Some(tab_rect) = tab_rects.get(current_index) {
+					let (start, end) = (self.along(tab_rect.min - viewport.min), self.along(tab_rect.max - viewport.max));
+					if start < 0. { scroll += start } else if end > 0. { scroll += end }
+				}
+
+				ui.memory_mut(|m| m.data.insert_temp(scrolled_to_id, current_index));
+			}
+
+			// Changes made here only show up next frame
+			let scroll = scroll.clamp(0., max_scroll);
+			if ui.memory_mut(|m| m.data.get_temp(scroll_id)) != Some(scroll) {
+				ui.memory_mut(|m| m.data.insert_temp(scroll_id, scroll));
+				ui.ctx().request_repaint();
+			}
+		}
+
+		// The add button doesn't scroll with the tabs, it's always at the end
+		let added = self.add_button && Self::plus_button(ui, self.id.with("add"), Rect::from_min_max(strip_rect.max - self.along_across(0., self.across(rect.size())), rect.max)).clicked();
+
+		// Everything in the strip has been added, so Tab can move focus past it now
+		ui.memory_mut(|m| {
+			m.lock_focus(self.id, false);
+			if !ctrl_tab { m.interested_in_focus(self.id) }
+		});
+
+		self.context_menu_entries = context_menu_entries;
+
+		let mut output = ConnectedTabsOutput { response, tab_responses, previous_index, index: current_index, closed, moved, added, renamed, menu_action };
+
+		// Keep the index pointing at the same tab once the tabs are closed, moved, or duplicated,
+		// if the selected tab itself is closed, the next one (or the previous, if it was the last) takes its place,
+		// or the targeted tab if it was closed from the menu
+		current_index = Self::shifted_index(current_index, &output).unwrap_or_else(|| match menu_action {
+			Some((target, _)) => Self::shifted_index(target, &output).unwrap_or_default(),
+			None => current_index.min(self.tabs.len().saturating_sub(2)),
+		});
+
+		// Tabs made with `from_values` store the value of the selected tab, which doesn't change when tabs shift around,
+		// so it's the value the selected tab had before shifting, the values themselves are only shifted by the caller
+		let selected_tab = if self.values_are_indices { current_index } else { self.unshifted_index(current_index, &output) };
+		self.set_index(ui, selected_tab);
+
+		output.index = current_index;
+		if current_index != previous_index { output.response.mark_changed() }
+
+		output
+	}
+
+	/// Where the selection indicator is this frame, sliding over from the previously selected tab if the selection is animated.
+	fn selection_rect(&self, ui: &Ui, tab_rects: &[Rect], index: usize) -> Option<Rect>
+	{
+		let target = *tab_rects.get(index)?;
+		let (animation_time, easing) = self.selection_animation;
+		if animation_time <= 0. { return Some(target) }
+
+		// The index itself is animated, a fractional index being in-between two tabs
+		let position = ui.ctx().animate_value_with_time(self.id.with("selection_position"), index as f32, animation_time);
+
+		// Easing needs to know where the animation started, which is wherever the indicator was when the selection changed
+		let start_id = self.id.with("selection_start");
+		let (mut start, animating_to) = ui.memory(|m| m.data.get_temp(start_id)).unwrap_or((position, index));
+		if animating_to != index { start = position }
+		ui.memory_mut(|m| m.data.insert_temp(start_id, (start, index)));
+
+		let distance = index as f32 - start;
+		let t = if distance == 0. { 1. } else { ((position - start) / distance).clamp(0., 1.) };
+		let position = (start + distance * easing.apply(t)).max(0.);
+
+		// Tabs may have been closed mid-animation, so the tabs around the position might not exist anymore
+		let from = tab_rects.get(position.floor() as usize).unwrap_or(&target);
+		let to = tab_rects.get(position.floor() as usize + 1).unwrap_or(from);
+		Some(from.lerp_towards(to, position.fract()))
+	}
+
+	/// Finds the closest selectable tab to `from`, searching in the direction of `step`, optionally wrapping around the ends.
+	fn next_selectable(&self, from: usize, step: isize, wrap: bool) -> Option<usize> {
+		let len = self.tabs.len() as isize;
+		(1..len)
+			.map(|distance| from as isize + step * distance)
+			.map(|i| if wrap { i.rem_euclid(len) } else { i })
+			.take_while(|i| (0..len).contains(i))
+			.map(|i| i as usize)
+			.find(|&i| self.tabs[i].selectable())
+	}
+
+	/// Accessibility info for the tab at `index`, including its position among the other tabs.
+	fn tab_info(&self, index: usize, selected: bool) -> WidgetInfo {
+		let (mut text, enabled) = self.tabs.get(index).map_or((String::new(), true), |tab| (tab.text.text().to_owned(), tab.enabled));
+		// Text badges are important enough to be read out too, such as unread counts
+		if let Some(TabBadge::Text(badge)) = self.tabs.get(index).and_then(|tab| tab.badge.as_ref()) {
+			text = format!("{text} ({})", badge.text());
+		}
+		WidgetInfo { enabled, ..WidgetInfo::selected(WidgetType::SelectableLabel, selected, format!("{text}, tab {} of {}", index + 1, self.tabs.len())) }
+	}
+
+	/// Paints the tab at `index` the same way as a [SelectableLabel] would (unless the look says otherwise), leaving `right_padding` at the right of `rect` empty.
+	fn tab_button(&self, ui: &mut Ui, index: usize, rect: Rect, selected: bool, right_padding: f32, style: &ConnectedTabsStyle) -> Response
+	{
+		// Tabs are skipped over when moving focus, the strip is focused instead, see `show_tabs`
+		let sense = if self.reorderable || self.detachable { Sense::click_and_drag() } else { Sense::click() };
+		let tab = &self.tabs[index];
+		let mut response = ui.interact(rect, self.id.with(index), sense);
+		response.widget_info(|| self.tab_info(index, selected));
+
+		if let Some(text) = &tab.hover_text { response = response.on_hover_text(text.clone()) }
+		if let Some(text) = &tab.disabled_hover_text { response = response.on_disabled_hover_text(text.clone()) }
+
+		if ui.is_rect_visible(rect)
+		{
+			let visuals = ui.style().interact_selectable(&response, selected);
+
+			let hovered = response.hovered() || response.highlighted() || response.has_focus();
+			match style.look
+			{
+				// Trapezoids narrow away from the contents, the selected one is filled the same as the contents frame
+				ConnectedTabsLook::Browser => {
+					let (frame_fill, stroke) = Self::browser_colors(ui, style);
+					let fill = if selected { frame_fill } else if hovered { visuals.weak_bg_fill } else { ui.visuals().widgets.inactive.weak_bg_fill };
+
+					let slant = self.across(rect.size()) / 4.;
+					let (start, end, near, far) = (self.along(rect.min.to_vec2()), self.along(rect.max.to_vec2()), self.across(rect.max.to_vec2()), self.across(rect.min.to_vec2()));
+					let points: Vec<Pos2> = [(start, near), (start + slant, far), (end - slant, far), (end, near)].into_iter()
+						.map(|(along, across)| self.along_across(along, across).to_pos2())
+						.collect();
+
+					ui.painter().add(Shape::convex_polygon(points.clone(), fill, Stroke::NONE));
+					ui.painter().add(Shape::line(points, stroke));
+				}
+				// With a pill, the selected background is painted separately, so it can slide between tabs, the underline look has no selected background at all
+				_ => {
+					let selected_bg = selected && style.look == ConnectedTabsLook::Segmented;
+					if selected_bg || (!selected && hovered) {
+						ui.painter().rect(rect.expand(visuals.expansion), visuals.rounding, visuals.weak_bg_fill, visuals.bg_stroke);
+					}
+				}
+			}
+
+			let mut content_rect = rect;
+			content_rect.max.x -= right_padding;
+			tab.layout(ui).paint(ui, content_rect, &visuals);
+		}
+
+		response
+	}
+
+	/// Paints a button used when the tabs overflow, an arrow pointing in `direction`, or three dots if there is no direction.
+	fn overflow_button(ui: &mut Ui, id: Id, rect: Rect, direction: Option<Vec2>) -> Response
+	{
+		let response = ui.interact(rect, id, Sense::click());
+
+		let visuals = ui.style().interact(&response);
+		if response.hovered() {
+			ui.painter().rect_filled(rect.expand(visuals.expansion), visuals.rounding, visuals.weak_bg_fill);
+		}
+
+		let (center, radius) = (rect.center(), ui.spacing().icon_width / 4.);
+		if let Some(direction) = direction {
+			let side = direction.rot90() * radius;
+			ui.painter().add(Shape::convex_polygon(vec![center + direction * radius, center - direction * radius + side, center - direction * radius - side], visuals.fg_stroke.color, Stroke::NONE));
+		}
+		else {
+			for offset in [-1.5, 0., 1.5] {
+				ui.painter().circle_filled(center + vec2(offset * radius, 0.), radius / 3., visuals.fg_stroke.color);
+			}
+		}
+
+		response
+	}
+
+	/// Paints a `+` that can be clicked to add a tab.
+	fn plus_button(ui: &mut Ui, id: Id, rect: Rect) -> Response
+	{
+		let response = ui.interact(rect, id, Sense::click());
+
+		let visuals = ui.style().interact(&response);
+		if response.hovered() {
+			ui.painter().rect_filled(rect.expand(visuals.expansion), visuals.rounding, visuals.weak_bg_fill);
+		}
+
+		let (center, radius) = (rect.center(), ui.spacing().icon_width / 3.);
+		ui.painter().line_segment([center - vec2(radius, 0.), center + vec2(radius, 0.)], visuals.fg_stroke);
+		ui.painter().line_segment([center - vec2(0., radius), center + vec2(0., radius)], visuals.fg_stroke);
+
+		response
+	}
+
+	/// Paints an `X` that can be clicked to close a tab, the same way as the close button of a [Window].
+	fn close_button(ui: &mut Ui, id: Id, rect: Rect) -> Response
+	{
+		let response = ui.interact(rect, id, Sense::click());
+
+		let visuals = ui.style().interact(&response);
+		let rect = rect.shrink(2.).expand(visuals.expansion);
+		ui.painter().line_segment([rect.left_top(), rect.right_bottom()], visuals.fg_stroke);
+		ui.painter().line_segment([rect.right_top(), rect.left_bottom()], visuals.fg_stroke);
+
+		response
+	}
+}
+
+impl<T: Clone + PartialEq> Widget for ConnectedTabs<'_, T>
+{
+	fn ui(mut self, ui: &mut Ui) -> Response
+	{
+		// There are no contents to show in windows, so tabs can't be detached
+		self.detachable = false;
+		let output = self.show_tabs(ui, true);
+		self.follow_shifts(ui, &output);
+		output.response
+	}
+}
\ No newline at end of file
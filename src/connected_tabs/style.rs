@@ -0,0 +1,119 @@
+use crate::*;
+
+/// The overall look of [ConnectedTabs], see [ConnectedTabsStyle].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ConnectedTabsLook
+{
+	/// Tabs sit next to each other on a shared background, the selected one is highlighted.
+	#[default]
+	Segmented,
+	/// Tabs have no background, the selected one is marked by a line under it.
+	Underline,
+	/// The selected tab's background is a pill that slides between tabs along with the selected line.
+	Pill,
+	/// Tabs are trapezoids attached to a frame around the contents shown by `show`, like the tabs of a web browser.
+	Browser,
+}
+
+/// How [ConnectedTabs] looks, can be set per instance with [ConnectedTabs::style], or for every instance with [ConnectedTabsStyle::set_global].
+///
+/// # Examples
+/// ```
+/// use egui_extended::prelude::*;
+/// use egui::*;
+///
+/// egui::__run_test_ui(|ui|
+/// {
+/// 	ConnectedTabsStyle::underline().set_global(ui.ctx());
+///
+/// 	ConnectedTabs::new("styled_tabs", false)
+/// 		.tab("Files")
+/// 		.tab("Search")
+/// 		.ui(ui);
+/// });
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectedTabsStyle
+{
+	pub look: ConnectedTabsLook,
+
+	/// The rounding of the tabs and the bg element, uses the rounding of the current visuals if `None`.
+	pub rounding: Option<Rounding>,
+	/// The color of the rectangle behind tab buttons, uses the fill of inactive widgets if `None`.
+	pub bg_fill: Option<Color32>,
+	/// The volume of the buttons.
+	pub outer_button_margin: Vec2,
+	/// The margin in-between buttons.
+	pub inner_button_margin: f32,
+	/// How much bigger or smaller the bg element is compared to the buttons.
+	pub bg_margin: f32,
+	/// A line drawn under the currently selected tab, width is a range from 0-1, 1 being 100% of the tab's width.
+	pub selected_line: (Stroke, f32),
+	pub selected_line_y_offset: f32,
+}
+
+impl Default for ConnectedTabsStyle
+{
+	fn default() -> Self {
+		Self::segmented()
+	}
+}
+
+impl ConnectedTabsStyle
+{
+	/// Tabs on a shared background, the default.
+	pub fn segmented() -> Self {
+		Self {
+			look: ConnectedTabsLook::Segmented,
+
+			rounding: None,
+			bg_fill: None,
+			outer_button_margin: vec2(20., 15.),
+			inner_button_margin: 3.,
+			bg_margin: 1.5,
+			selected_line: (Stroke::NONE, 0.),
+			selected_line_y_offset: 0.,
+		}
+	}
+
+	/// Tabs without a background, the selected one is marked by a line under it in the selection color.
+	pub fn underline() -> Self {
+		Self {
+			look: ConnectedTabsLook::Underline,
+			inner_button_margin: 0.,
+			selected_line_y_offset: 1.,
+			..Self::segmented()
+		}
+	}
+
+	/// Fully rounded tabs, with a pill behind the selected one.
+	pub fn pill() -> Self {
+		Self {
+			look: ConnectedTabsLook::Pill,
+			rounding: Some(Rounding::same(f32::INFINITY)),
+			..Self::segmented()
+		}
+	}
+
+	/// Trapezoid tabs attached to a frame around the contents.
+	pub fn browser() -> Self {
+		Self {
+			look: ConnectedTabsLook::Browser,
+			inner_button_margin: 0.,
+			bg_margin: 0.,
+			..Self::segmented()
+		}
+	}
+
+	/// Uses this style for every instance of [ConnectedTabs] that doesn't have its own style.
+	pub fn set_global(self, ctx: &Context) {
+		ctx.data_mut(|data| data.insert_temp(Id::new("egui_extended::ConnectedTabsStyle"), self));
+	}
+
+	/// The style used by instances of [ConnectedTabs] that don't have their own, see `set_global`.
+	pub fn global(ctx: &Context) -> Self {
+		ctx.data_mut(|data| data.get_temp(Id::new("egui_extended::ConnectedTabsStyle"))).unwrap_or_default()
+	}
+}
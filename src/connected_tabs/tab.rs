@@ -0,0 +1,239 @@
+use crate::*;
+use egui::widget_text::WidgetTextGalley;
+
+/// Shown before the text of a [Tab].
+#[derive(Clone)]
+pub enum TabIcon
+{
+	/// Usually a single emoji or icon font character.
+	Glyph(WidgetText),
+	Image(TextureId, Vec2),
+}
+
+/// Shown after the text of a [Tab], to draw attention to it.
+#[derive(Clone)]
+pub enum TabBadge
+{
+	/// Text in a small bubble, such as an unread count.
+	Text(WidgetText),
+	/// A small dot, such as for unsaved changes.
+	Dot,
+}
+
+/// A single tab of [ConnectedTabs], anything that can be turned into [WidgetText] can be used in its place.
+///
+/// # Examples
+/// ```
+/// use egui_extended::prelude::*;
+/// use egui::*;
+///
+/// let file_loaded = false;
+///
+/// egui::__run_test_ui(|ui|
+/// {
+/// 	ConnectedTabs::new("file_tabs", false)
+/// 		.tab("Open")
+/// 		.tab(Tab::new("Export")
+/// 			.enabled(file_loaded)
+/// 			.disabled_hover_text("Load a file first"))
+/// 		.ui(ui);
+/// });
+/// ```
+#[derive(Clone)]
+pub struct Tab
+{
+	pub(crate) text: WidgetText,
+	pub(crate) id: Option<Id>,
+	pub(crate) icon: Option<TabIcon>,
+	pub(crate) badge: Option<TabBadge>,
+
+	pub(crate) enabled: bool,
+	pub(crate) visible: bool,
+	pub(crate) hover_text: Option<WidgetText>,
+	pub(crate) disabled_hover_text: Option<WidgetText>,
+}
+
+impl Tab
+{
+	pub fn new(text: impl Into<WidgetText>) -> Self {
+		Self {
+			text: text.into(),
+			id: None,
+			icon: None,
+			badge: None,
+
+			enabled: true,
+			visible: true,
+			hover_text: None,
+			disabled_hover_text: None,
+		}
+	}
+
+	crate::builder_set!{/// Tells this tab apart from the others, so state such as that of its contents in `show_lazy`, or of the window it is detached into, follows it when tabs are closed or moved (default: its index)
+		id_source => id: impl std::hash::Hash => Some(Id::new(id))}
+
+	crate::builder_set!{/// Sets the icon shown before the text
+		icon: TabIcon => Some(icon)}
+	crate::builder_set!{/// Shows a glyph, such as an emoji, before the text
+		glyph_icon => icon: impl Into<WidgetText> => Some(TabIcon::Glyph(icon.into()))}
+	/// Shows an image before the text
+	pub fn image_icon(mut self, texture_id: impl Into<TextureId>, size: impl Into<Vec2>) -> Self {
+		self.icon = Some(TabIcon::Image(texture_id.into(), size.into()));
+		self
+	}
+
+	crate::builder_set!{/// Sets the badge shown after the text
+		badge: TabBadge => Some(badge)}
+	crate::builder_set!{/// Shows text in a bubble after the text, such as an unread count
+		text_badge => badge: impl Into<WidgetText> => Some(TabBadge::Text(badge.into()))}
+	/// Shows a dot after the text, such as for unsaved changes
+	pub fn dot_badge(mut self) -> Self {
+		self.badge = Some(TabBadge::Dot);
+		self
+	}
+
+	crate::builder_set!{/// Disabled tabs are grayed out, and can't be selected (default: true)
+		enabled: bool}
+	crate::builder_set!{/// Invisible tabs aren't shown at all, and can't be selected (default: true)
+		visible: bool}
+	crate::builder_set!{/// Text shown when hovering over the tab while it's enabled
+		hover_text: impl Into<WidgetText> => Some(hover_text.into())}
+	crate::builder_set!{/// Text shown when hovering over the tab while it's disabled, usually the reason it's disabled
+		disabled_hover_text: impl Into<WidgetText> => Some(disabled_hover_text.into())}
+
+	/// Whether this tab can be selected.
+	pub(crate) fn selectable(&self) -> bool {
+		self.enabled && self.visible
+	}
+
+	/// Lays out the icon, text, and badge of this tab, so they can be measured and painted.
+	pub(crate) fn layout(&self, ui: &Ui) -> TabLayout {
+		TabLayout {
+			icon: self.icon.clone().map(|icon| match icon {
+				TabIcon::Glyph(glyph) => IconLayout::Glyph(glyph.into_galley(ui, None, f32::MAX, TextStyle::Button)),
+				TabIcon::Image(texture_id, size) => IconLayout::Image(texture_id, size),
+			}),
+			text: self.text.clone().into_galley(ui, None, f32::MAX, TextStyle::Button),
+			badge: self.badge.clone().map(|badge| match badge {
+				TabBadge::Text(text) => BadgeLayout::Text(text.into_galley(ui, None, f32::MAX, TextStyle::Small)),
+				TabBadge::Dot => BadgeLayout::Dot,
+			}),
+			spacing: ui.spacing().icon_spacing,
+		}
+	}
+}
+
+pub(crate) enum IconLayout
+{
+	Glyph(WidgetTextGalley),
+	Image(TextureId, Vec2),
+}
+
+pub(crate) enum BadgeLayout
+{
+	Text(WidgetTextGalley),
+	Dot,
+}
+
+/// The laid out contents of a [Tab], see [Tab::layout].
+pub(crate) struct TabLayout
+{
+	icon: Option<IconLayout>,
+	text: WidgetTextGalley,
+	badge: Option<BadgeLayout>,
+	spacing: f32,
+}
+
+impl TabLayout
+{
+	const BADGE_PADDING: f32 = 3.;
+	const DOT_SIZE: f32 = 6.;
+
+	fn icon_size(&self) -> Option<Vec2> {
+		self.icon.as_ref().map(|icon| match icon {
+			IconLayout::Glyph(galley) => galley.size(),
+			IconLayout::Image(_, size) => *size,
+		})
+	}
+
+	fn badge_size(&self) -> Option<Vec2> {
+		self.badge.as_ref().map(|badge| match badge {
+			// Bubbles are never thinner than they are tall, so short badges are round
+			BadgeLayout::Text(galley) => {
+				let size = galley.size() + vec2(Self::BADGE_PADDING * 2., 0.);
+				vec2(size.x.max(size.y), size.y)
+			}
+			BadgeLayout::Dot => Vec2::splat(Self::DOT_SIZE),
+		})
+	}
+
+	/// The size of everything in a row, with spacing in-between.
+	pub(crate) fn size(&self) -> Vec2 {
+		[self.icon_size(), Some(self.text.size()), self.badge_size()].into_iter().flatten()
+			.fold(None, |total: Option<Vec2>, size| Some(match total {
+				Some(total) => vec2(total.x + self.spacing + size.x, total.y.max(size.y)),
+				None => size,
+			}))
+			.unwrap_or_default()
+	}
+
+	/// Paints everything in a row, centered in `rect`.
+	pub(crate) fn paint(self, ui: &mut Ui, rect: Rect, visuals: &WidgetVisuals) {
+		let (size, spacing, badge_size) = (self.size(), self.spacing, self.badge_size());
+		let mut x = rect.center().x - size.x / 2.;
+		let mut next_rect = |item_size: Vec2| {
+			let item_rect = Rect::from_min_size(pos2(x, rect.center().y - item_size.y / 2.), item_size);
+			x += item_size.x + spacing;
+			item_rect
+		};
+
+		if let (Some(icon), Some(icon_size)) = (&self.icon, self.icon_size()) {
+			let icon_rect = next_rect(icon_size);
+			match icon {
+				IconLayout::Glyph(galley) => galley.clone().paint_with_visuals(ui.painter(), icon_rect.min, visuals),
+				IconLayout::Image(texture_id, size) => Image::new(*texture_id, *size).paint_at(ui, icon_rect),
+			}
+		}
+
+		let text_rect = next_rect(self.text.size());
+		self.text.paint_with_visuals(ui.painter(), text_rect.min, visuals);
+
+		if let (Some(badge), Some(badge_size)) = (self.badge, badge_size) {
+			let badge_rect = next_rect(badge_size);
+			match badge {
+				BadgeLayout::Text(galley) => {
+					ui.painter().rect_filled(badge_rect, badge_rect.height() / 2., visuals.fg_stroke.color);
+					let text_pos = badge_rect.center() - galley.size() / 2.;
+					galley.paint_with_color_override(ui.painter(), text_pos, ui.visuals().panel_fill);
+				}
+				BadgeLayout::Dot => ui.painter().circle_filled(badge_rect.center(), Self::DOT_SIZE / 2., visuals.fg_stroke.color),
+			}
+		}
+	}
+}
+
+impl From<WidgetText> for Tab {
+	fn from(value: WidgetText) -> Self {
+		Self::new(value)
+	}
+}
+impl From<RichText> for Tab {
+	fn from(value: RichText) -> Self {
+		Self::new(value)
+	}
+}
+impl From<&str> for Tab {
+	fn from(value: &str) -> Self {
+		Self::new(value)
+	}
+}
+impl From<&String> for Tab {
+	fn from(value: &String) -> Self {
+		Self::new(value)
+	}
+}
+impl From<String> for Tab {
+	fn from(value: String) -> Self {
+		Self::new(value)
+	}
+}
\ No newline at end of file
@@ -0,0 +1,269 @@
+use super::*;
+
+use crate::test_harness::Harness;
+
+#[test]
+fn clicking_selects_tab()
+{
+	let mut harness = Harness::new();
+	let mut index = 0;
+	let mut show = |ui: &mut Ui| ConnectedTabs::new("tabs", false).tab("A").tab("B").tab("C").index(&mut index).show(ui, |_, _| {});
+
+	let output = harness.frame(vec![], &mut show);
+	let c = output.tab_response(2).unwrap().rect.center();
+	let output = harness.click(c, &mut show);
+
+	assert!(output.changed());
+	assert_eq!(output.index, 2);
+	drop(show);
+	assert_eq!(index, 2);
+}
+
+#[test]
+fn closing_tab_removes_it()
+{
+	let mut harness = Harness::new();
+	let mut tabs = vec!["A", "B", "C"];
+	let mut show = |ui: &mut Ui| ConnectedTabs::new("tabs", false).closable(true).show_vec_mut(ui, &mut tabs, |tab| (*tab).into(), |_, _| {});
+
+	let output = harness.frame(vec![], &mut show);
+	let b = output.tab_response(1).unwrap().rect;
+	// The close button is on the right side of the tab
+	let output = harness.click(pos2(b.right() - 10., b.center().y), &mut show);
+
+	assert_eq!(output.closed, Some(1));
+	drop(show);
+	assert_eq!(tabs, ["A", "C"]);
+}
+
+#[test]
+fn dragging_reorders_tabs()
+{
+	let mut harness = Harness::new();
+	let mut tabs = vec!["A", "B", "C"];
+	let mut show = |ui: &mut Ui| ConnectedTabs::new("tabs", false).reorderable(true).show_vec_mut(ui, &mut tabs, |tab| (*tab).into(), |_, _| {});
+
+	let output = harness.frame(vec![], &mut show);
+	let (a, c) = (output.tab_response(0).unwrap().rect.center(), output.tab_response(2).unwrap().rect.center());
+	let output = harness.drag(a, c, &mut show);
+
+	assert_eq!(output.moved, Some((0, 2)));
+	assert_eq!(output.index, 2);
+	drop(show);
+	assert_eq!(tabs, ["B", "C", "A"]);
+}
+
+#[test]
+fn values_follow_closed_tabs()
+{
+	let mut harness = Harness::new();
+	let mut page = 'C';
+	let mut pages = vec!['A', 'B', 'C'];
+
+	// The caller removes closed tabs, so the tabs change every frame
+	let mut show = |ui: &mut Ui| {
+		let output = ConnectedTabs::from_values("tabs", false, &mut page, pages.iter().map(|&page| (page, page.to_string())))
+			.closable(true)
+			.show(ui, |_, _| {});
+		if let Some(closed) = output.closed { pages.remove(closed); }
+		output
+	};
+
+	let output = harness.frame(vec![], &mut show);
+	let a = output.tab_response(0).unwrap().rect;
+	let output = harness.click(pos2(a.right() - 10., a.center().y), &mut show);
+
+	assert_eq!(output.closed, Some(0));
+	drop(show);
+	assert_eq!(pages, ['B', 'C']);
+	assert_eq!(page, 'C');
+}
+
+#[test]
+fn values_follow_moved_tabs()
+{
+	let mut harness = Harness::new();
+	let mut page = 'A';
+	let mut pages = vec!['A', 'B', 'C'];
+
+	let mut show = |ui: &mut Ui| {
+		let output = ConnectedTabs::from_values("tabs", false, &mut page, pages.iter().map(|&page| (page, page.to_string())))
+			.reorderable(true)
+			.show(ui, |_, _| {});
+		if let Some((from, to)) = output.moved { let page = pages.remove(from); pages.insert(to, page); }
+		output
+	};
+
+	let output = harness.frame(vec![], &mut show);
+	let (a, c) = (output.tab_response(0).unwrap().rect.center(), output.tab_response(2).unwrap().rect.center());
+	let output = harness.drag(a, c, &mut show);
+
+	assert_eq!(output.moved, Some((0, 2)));
+	drop(show);
+	assert_eq!(pages, ['B', 'C', 'A']);
+	assert_eq!(page, 'A');
+}
+
+#[test]
+fn dragging_away_detaches_tab()
+{
+	let mut harness = Harness::new();
+	let mut show = |ui: &mut Ui| ConnectedTabs::new("tabs", false).detachable(true).tab("A").tab("B").show(ui, |_, _| {});
+
+	let output = harness.frame(vec![], &mut show);
+	let a = output.tab_response(0).unwrap().rect.center();
+	harness.drag(a, a + vec2(0., 300.), &mut show);
+	let output = harness.frame(vec![], &mut show);
+
+	// Detached tabs aren't shown in the strip
+	assert!(output.tab_response(0).is_none());
+	assert!(output.tab_response(1).is_some());
+}
+
+#[test]
+fn showing_only_the_strip_keeps_tabs_attached()
+{
+	let mut harness = Harness::new();
+	let mut show_strip = |ui: &mut Ui| ui.add(ConnectedTabs::new("tabs", false).detachable(true).tab("A").tab("B"));
+	let response = harness.frame(vec![], &mut show_strip);
+	let a = response.rect.left_center() + vec2(10., 0.);
+	harness.drag(a, a + vec2(0., 300.), &mut show_strip);
+
+	// There would be no window to show the tab in, so it stays in the strip
+	let output = harness.frame(vec![], |ui| ConnectedTabs::new("tabs", false).detachable(true).tab("A").tab("B").show(ui, |_, _| {}));
+	assert!(output.tab_response(0).is_some());
+}
+
+#[test]
+fn cross_fade_shows_both_tabs()
+{
+	let mut harness = Harness::new();
+	// Returns the tabs whose contents were drawn
+	let mut show = |ui: &mut Ui| {
+		let mut drawn = Vec::new();
+		let output = ConnectedTabs::new("tabs", false)
+			.content_transition(ConnectedTabsTransition::CrossFade, 0.5)
+			.tab("A")
+			.tab("B")
+			.show(ui, |_, i| drawn.push(i));
+		(output, drawn)
+	};
+
+	let (output, _) = harness.frame(vec![], &mut show);
+	let b = output.tab_response(1).unwrap().rect.left_center() + vec2(5., 0.);
+	harness.click(b, &mut show);
+	let (_, drawn) = harness.frame(vec![], &mut show);
+	assert!(drawn.contains(&0) && drawn.contains(&1));
+
+	// Once the transition is over, only the selected tab is left
+	for _ in 0..40 { harness.frame(vec![], &mut show); }
+	let (_, drawn) = harness.frame(vec![], &mut show);
+	assert_eq!(drawn, [1]);
+}
+
+#[test]
+fn lazy_tabs_are_hidden_after_transition()
+{
+	let mut harness = Harness::new();
+	let mut lifecycles = Vec::new();
+	let mut show = |ui: &mut Ui| ConnectedTabs::new("tabs", false)
+		.content_transition(ConnectedTabsTransition::CrossFade, 0.5)
+		.tab("A")
+		.tab("B")
+		.show_lazy(ui, |_, i, lifecycle| lifecycles.push((i, lifecycle)));
+
+	let output = harness.frame(vec![], &mut show);
+	let b = output.tab_response(1).unwrap().rect.left_center() + vec2(5., 0.);
+	harness.click(b, &mut show);
+	for _ in 0..40 { harness.frame(vec![], &mut show); }
+	drop(show);
+
+	// The first tab keeps being shown while it fades out, and is only hidden once, when it is gone
+	let a: Vec<_> = lifecycles.iter().filter(|(i, _)| *i == 0).map(|&(_, lifecycle)| lifecycle).collect();
+	let hidden = a.iter().position(|&lifecycle| lifecycle == TabLifecycle::Hidden).unwrap();
+	assert_eq!(a[0], TabLifecycle::FirstShown);
+	assert!(a[1..hidden].iter().all(|&lifecycle| lifecycle == TabLifecycle::Shown));
+	assert!(hidden > 10);
+	assert_eq!(hidden, a.len() - 1);
+	assert!(lifecycles.contains(&(1, TabLifecycle::FirstShown)));
+}
+
+#[test]
+fn selection_animation_eases()
+{
+	// Where the selection is a few frames into moving from the first tab to the second
+	let position_with = |easing| {
+		let mut harness = Harness::new();
+		let tabs = ConnectedTabs::new("tabs", false).selection_animation(0.5, easing);
+		let tab_rects = [Rect::from_min_size(Pos2::ZERO, vec2(100., 20.)), Rect::from_min_size(pos2(100., 0.), vec2(100., 20.))];
+		harness.frame(vec![], |ui| tabs.selection_rect(ui, &tab_rects, 0));
+		(0..10).map(|_| harness.frame(vec![], |ui| tabs.selection_rect(ui, &tab_rects, 1).unwrap().left())).last().unwrap()
+	};
+
+	let (linear, ease_in) = (position_with(Easing::Linear), position_with(Easing::EaseIn));
+	assert!(0. < ease_in && ease_in < linear && linear < 100., "linear: {linear}, ease in: {ease_in}");
+}
+
+#[test]
+fn tab_key_moves_focus_past_tabs()
+{
+	let mut harness = Harness::new();
+	// Close buttons are focusable too, but should be skipped over all the same
+	let mut show = |ui: &mut Ui| (ConnectedTabs::new("tabs", false).closable(true).tab("A").tab("B").show(ui, |_, _| {}), ui.button("After"));
+
+	let (output, _) = harness.frame(vec![], &mut show);
+	// Clicking a tab focuses the strip, away from the close button on the right
+	let a = output.tab_response(0).unwrap().rect.left_center() + vec2(5., 0.);
+	harness.click(a, &mut show);
+	let (output, _) = harness.frame(vec![], &mut show);
+	assert!(output.response.has_focus());
+
+	// The arrow keys select tabs while the strip is focused
+	let key = |key| Event::Key { key, pressed: true, repeat: false, modifiers: Modifiers::NONE };
+	let (output, _) = harness.frame(vec![key(Key::ArrowRight)], &mut show);
+	assert_eq!(output.index, 1);
+
+	harness.frame(vec![key(Key::Tab)], &mut show);
+	let (output, after) = harness.frame(vec![], &mut show);
+	assert!(!output.response.has_focus());
+	assert!(after.has_focus());
+
+	// Going back focuses the strip again, instead of the tabs or their close buttons
+	harness.frame(vec![Event::Key { key: Key::Tab, pressed: true, repeat: false, modifiers: Modifiers::SHIFT }], &mut show);
+	let (output, _) = harness.frame(vec![], &mut show);
+	assert!(output.response.has_focus());
+}
+
+fn output_with(closed: Option<usize>, moved: Option<(usize, usize)>, menu_action: Option<(usize, ConnectedTabsMenuAction)>) -> ConnectedTabsOutput {
+	let response = Harness::new().frame(vec![], |ui| ui.label(""));
+	ConnectedTabsOutput { response, tab_responses: Vec::new(), previous_index: 0, index: 0, closed, moved, added: false, renamed: None, menu_action }
+}
+
+#[test]
+fn shifted_index()
+{
+	let shifted = |output: &ConnectedTabsOutput| (0..4).map(|i| ConnectedTabs::<usize>::shifted_index(i, output)).collect::<Vec<_>>();
+
+	assert_eq!(shifted(&output_with(Some(1), None, None)), [Some(0), None, Some(1), Some(2)]);
+	assert_eq!(shifted(&output_with(None, Some((0, 2)), None)), [Some(2), Some(0), Some(1), Some(3)]);
+	assert_eq!(shifted(&output_with(None, Some((3, 1)), None)), [Some(0), Some(2), Some(3), Some(1)]);
+	assert_eq!(shifted(&output_with(None, None, Some((2, ConnectedTabsMenuAction::CloseOthers)))), [None, None, Some(0), None]);
+	assert_eq!(shifted(&output_with(None, None, Some((1, ConnectedTabsMenuAction::CloseToTheRight)))), [Some(0), Some(1), None, None]);
+	assert_eq!(shifted(&output_with(None, None, Some((1, ConnectedTabsMenuAction::Duplicate)))), [Some(0), Some(1), Some(3), Some(4)]);
+}
+
+#[test]
+fn next_selectable()
+{
+	let tabs = ConnectedTabs::new("tabs", false)
+		.tab("A")
+		.tab(Tab::new("B").enabled(false))
+		.tab("C")
+		.tab(Tab::new("D").visible(false));
+
+	assert_eq!(tabs.next_selectable(0, 1, false), Some(2));
+	assert_eq!(tabs.next_selectable(2, 1, false), None);
+	assert_eq!(tabs.next_selectable(2, 1, true), Some(0));
+	assert_eq!(tabs.next_selectable(0, -1, false), None);
+	assert_eq!(tabs.next_selectable(0, -1, true), Some(2));
+}
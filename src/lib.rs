@@ -7,6 +7,8 @@ pub mod switch;
 pub mod custom_frame;
 
 pub(crate) mod internal;
+#[cfg(test)]
+pub(crate) mod test_harness;
 
 pub(crate) use internal::*;
 pub(crate) use crate::prelude::*;
@@ -24,6 +26,34 @@ pub enum WidgetState
 	Open,
 }
 
+/// Curves used to make animations look more natural, see [Easing::apply].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing
+{
+	#[default]
+	Linear,
+	/// Starts slow, then speeds up.
+	EaseIn,
+	/// Starts fast, then slows down.
+	EaseOut,
+	/// Starts slow, speeds up, then slows down again.
+	EaseInOut,
+}
+
+impl Easing
+{
+	/// Maps `t` from 0-1 onto this curve.
+	pub fn apply(self, t: f32) -> f32 {
+		match self {
+			Self::Linear => t,
+			Self::EaseIn => t * t * t,
+			Self::EaseOut => 1. - (1. - t).powi(3),
+			Self::EaseInOut => if t < 0.5 { 4. * t * t * t } else { 1. - (-2. * t + 2.).powi(3) / 2. },
+		}
+	}
+}
+
 pub trait WidgetsExtension
 {
 	/// Sets rounding for all widget states
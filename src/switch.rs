@@ -1,45 +1,546 @@
 use crate::*;
 
+/// Colors of a [Switch] in a single [WidgetState], colors that aren't set are taken from the current visuals.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SwitchVisuals
+{
+	pub track_off_fill: Option<Color32>,
+	pub track_on_fill: Option<Color32>,
+	pub knob_fill: Option<Color32>,
+	pub knob_stroke: Option<Stroke>,
+}
+
+/// How a [Switch] looks in each [WidgetState], can be set per instance with [Switch::style], or for every instance with [SwitchStyle::set_global].
+///
+/// # Examples
+/// ```
+/// use egui_extended::prelude::*;
+/// use egui::*;
+///
+/// let mut armed = false;
+///
+/// egui::__run_test_ui(|ui|
+/// {
+/// 	let mut danger = SwitchStyle::default();
+/// 	danger.modify_all(|visuals| visuals.track_on_fill = Some(Color32::RED));
+///
+/// 	Switch::new(&mut armed).style(danger).ui(ui);
+/// });
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SwitchStyle
+{
+	pub active: SwitchVisuals,
+	pub hovered: SwitchVisuals,
+	pub inactive: SwitchVisuals,
+	pub noninteractive: SwitchVisuals,
+}
+
+impl SwitchStyle
+{
+	/// Modifies the visuals of every state using the specified modifier
+	pub fn modify_all(&mut self, modifier: impl Fn(&mut SwitchVisuals)) {
+		modifier(&mut self.active);
+		modifier(&mut self.hovered);
+		modifier(&mut self.inactive);
+		modifier(&mut self.noninteractive);
+	}
+
+	/// Returns the [SwitchVisuals] with the specified state, switches are never open, so that uses the active visuals
+	pub fn get_state(&self, state: WidgetState) -> &SwitchVisuals {
+		match state {
+			WidgetState::Active | WidgetState::Open => &self.active,
+			WidgetState::Hovered => &self.hovered,
+			WidgetState::Inactive => &self.inactive,
+			WidgetState::Noninteractive => &self.noninteractive,
+		}
+	}
+
+	/// Returns the [SwitchVisuals] with the specified state, switches are never open, so that uses the active visuals
+	pub fn get_state_mut(&mut self, state: WidgetState) -> &mut SwitchVisuals {
+		match state {
+			WidgetState::Active | WidgetState::Open => &mut self.active,
+			WidgetState::Hovered => &mut self.hovered,
+			WidgetState::Inactive => &mut self.inactive,
+			WidgetState::Noninteractive => &mut self.noninteractive,
+		}
+	}
+
+	/// Uses this style for every [Switch] that doesn't have its own style.
+	pub fn set_global(self, ctx: &Context) {
+		ctx.data_mut(|data| data.insert_temp(Id::new("egui_extended::SwitchStyle"), self));
+	}
+
+	/// The style used by switches that don't have their own, see `set_global`.
+	pub fn global(ctx: &Context) -> Self {
+		ctx.data_mut(|data| data.get_temp(Id::new("egui_extended::SwitchStyle"))).unwrap_or_default()
+	}
+}
+
 /// Copied from the Widget gallery example code
+///
+/// # Examples
+/// ```
+/// use egui_extended::prelude::*;
+/// use egui::*;
+///
+/// let mut on = false;
+///
+/// egui::__run_test_ui(|ui|
+/// {
+/// 	Switch::new(&mut on)
+/// 		.size([50., 20.])
+/// 		.knob_ratio(0.6)
+/// 		.ui(ui);
+///
+/// 	Switch::new(&mut on)
+/// 		.text("Dark mode")
+/// 		.track_text("OFF", "ON")
+/// 		.ui(ui);
+///
+/// 	// Expanding switches fill the size given by `WidgetPlacement`
+/// 	Switch::new(&mut on)
+/// 		.expand(true)
+/// 		.place()
+/// 			.size([60., 30.])
+/// 			.ui(ui);
+/// });
+/// ```
 pub struct Switch<'a>
 {
-	on: &'a mut bool,
+	value: SwitchValue<'a>,
+	text: Option<WidgetText>,
+	text_left: bool,
+	track_text: Option<(WidgetText, WidgetText)>,
+	icons: Option<(WidgetText, WidgetText)>,
+
+	width: Option<f32>,
+	height: Option<f32>,
+	expand: bool,
+	knob_ratio: f32,
+	knob_padding: Option<f32>,
+	track_rounding: Option<Rounding>,
+	style: Option<SwitchStyle>,
+	pending: bool,
+}
+
+/// What a [Switch] is bound to.
+enum SwitchValue<'a>
+{
+	Bool(&'a mut bool),
+	/// `None` is the indeterminate state.
+	TriState(&'a mut Option<bool>),
+	/// A value of any type that is either the off or on value, see [Switch::between].
+	/// Values that are neither show as indeterminate.
+	Between(Option<bool>, Box<dyn FnMut(bool) + 'a>),
+}
+
+impl SwitchValue<'_>
+{
+	fn get(&self) -> Option<bool> {
+		match self {
+			Self::Bool(on) => Some(**on),
+			Self::TriState(on) => **on,
+			Self::Between(on, _) => *on,
+		}
+	}
+
+	fn set(&mut self, value: Option<bool>) {
+		match self {
+			Self::Bool(on) => if let Some(value) = value { **on = value },
+			Self::TriState(on) => **on = value,
+			Self::Between(on, set) => if let Some(value) = value {
+				*on = Some(value);
+				set(value);
+			}
+		}
+	}
+
+	/// The value after clicking, tri-state switches go from indeterminate, to on, to off, and back to indeterminate.
+	fn next(&self) -> Option<bool> {
+		match (self, self.get()) {
+			(Self::TriState(_), Some(false)) => None,
+			(_, Some(on)) => Some(!on),
+			(_, None) => Some(true),
+		}
+	}
 }
 
 impl<'a> Switch<'a>
 {
 	pub fn new(on: &'a mut bool) -> Self {
+		Self::new_with_value(SwitchValue::Bool(on))
+	}
+
+	/// Creates a switch with an indeterminate state (`None`), such as for a setting that is on for some selected items but not others.
+	/// The knob sits in the middle while indeterminate, and clicking cycles through on, off, and indeterminate.
+	///
+	/// # Examples
+	/// ```
+	/// use egui_extended::prelude::*;
+	/// use egui::*;
+	///
+	/// let mut visible: Option<bool> = None;
+	///
+	/// egui::__run_test_ui(|ui|
+	/// {
+	/// 	Switch::tri_state(&mut visible).text("Visible").ui(ui);
+	/// });
+	/// ```
+	pub fn tri_state(on: &'a mut Option<bool>) -> Self {
+		Self::new_with_value(SwitchValue::TriState(on))
+	}
+
+	/// Creates a switch that toggles `value` between two values of any type, such as two variants of an enum.
+	/// If `value` is neither, the switch shows as indeterminate until it is clicked, which sets it to `on`.
+	///
+	/// # Examples
+	/// ```
+	/// use egui_extended::prelude::*;
+	/// use egui::*;
+	///
+	/// #[derive(Clone, PartialEq)]
+	/// enum Theme { Light, Dark }
+	///
+	/// let mut theme = Theme::Light;
+	///
+	/// egui::__run_test_ui(|ui|
+	/// {
+	/// 	Switch::between(&mut theme, Theme::Light, Theme::Dark)
+	/// 		.icons("☀", "🌙")
+	/// 		.text("Theme")
+	/// 		.ui(ui);
+	/// });
+	/// ```
+	pub fn between<T: PartialEq + Clone + 'a>(value: &'a mut T, off: T, on: T) -> Self {
+		let current = if *value == on { Some(true) } else if *value == off { Some(false) } else { None };
+		Self::new_with_value(SwitchValue::Between(current, Box::new(move |new_on| *value = if new_on { on.clone() } else { off.clone() })))
+	}
+
+	fn new_with_value(value: SwitchValue<'a>) -> Self {
 		Self {
-			on,
+			value,
+			text: None,
+			text_left: false,
+			track_text: None,
+			icons: None,
+
+			width: None,
+			height: None,
+			expand: false,
+			knob_ratio: 0.75,
+			knob_padding: None,
+			track_rounding: None,
+			style: None,
+			pending: false,
 		}
 	}
+
+	crate::builder_set!{/// A label shown next to the switch, clicking it toggles the switch too
+		text: impl Into<WidgetText> => Some(text.into())}
+	crate::builder_set!{/// Shows the label on the left of the switch instead of the right (default: false)
+		text_left: bool}
+
+	/// Shows text (or glyphs) inside the track, in the space the knob isn't taking up, such as "OFF" and "ON".
+	pub fn track_text(mut self, off: impl Into<WidgetText>, on: impl Into<WidgetText>) -> Self {
+		self.track_text = Some((off.into(), on.into()));
+		self
+	}
+
+	/// Shows icons (usually glyphs) at both ends of the track, under where the knob sits while off and on, so the knob covers the current one.
+	pub fn icons(mut self, off: impl Into<WidgetText>, on: impl Into<WidgetText>) -> Self {
+		self.icons = Some((off.into(), on.into()));
+		self
+	}
+
+	crate::builder_set!{/// Sets the width of the track (default: twice the height)
+		width: f32 => Some(width)}
+	crate::builder_set!{/// Sets the height of the track (default: the height of interactive widgets)
+		height: f32 => Some(height)}
+
+	/// Sets both the width and height of the track.
+	pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+		let size = size.into();
+		(self.width, self.height) = (Some(size.x), Some(size.y));
+		self
+	}
+	crate::builder_set!{/// Without a width or height of its own, the track fills the space a justified layout gives it, such as by `WidgetPlacement::size` (default: false)
+		expand: bool}
+
+	crate::builder_set!{/// The diameter of the knob compared to the height of the track (default: 0.75)
+		knob_ratio: f32}
+	crate::builder_set!{/// The space between the knob and the ends of the track (default: the same as the space above and below the knob)
+		knob_padding: f32 => Some(knob_padding)}
+	crate::builder_set!{/// The rounding of the track (default: fully rounded)
+		track_rounding: impl Into<Rounding> => Some(track_rounding.into())}
+	crate::builder_set!{/// Sets the colors of this switch, if not set, the global style is used, see [SwitchStyle::set_global]
+		style: SwitchStyle => Some(style)}
+	crate::builder_set!{/// Shows a spinner in the knob and ignores input, such as while a change is being applied somewhere else (default: false)
+		pending: bool}
+
+	/// Shows this switch without changing its value, instead returning the value the user asked for, if any.
+	/// The value can then be written once whatever the switch controls confirms the change, with the switch marked as `pending` in the meantime.
+	///
+	/// Tri-state switches never ask to become indeterminate, clicking them while off asks for on instead.
+	///
+	/// # Examples
+	/// ```
+	/// use egui_extended::prelude::*;
+	/// use egui::*;
+	///
+	/// let mut wifi = false;
+	/// let mut waiting_for_wifi = false;
+	///
+	/// egui::__run_test_ui(|ui|
+	/// {
+	/// 	let (_, requested) = Switch::new(&mut wifi).text("Wi-Fi").pending(waiting_for_wifi).show_request(ui);
+	/// 	if let Some(_on) = requested {
+	/// 		// Start turning wifi on or off, then write the new value to `wifi` and clear `waiting_for_wifi` once done
+	/// 		waiting_for_wifi = true;
+	/// 	}
+	/// });
+	/// ```
+	pub fn show_request(self, ui: &mut Ui) -> (Response, Option<bool>) {
+		let (response, requested) = self.show(ui, false);
+		(response, requested.map(|on| on.unwrap_or(true)))
+	}
 }
 
 impl<'a> Widget for Switch<'a> {
 	fn ui(self, ui: &mut Ui) -> Response
 	{
-		// TODO: Support resizing
-		let desired_size = ui.spacing().interact_size.y * vec2(2.0, 1.0);
-		let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
-		if response.clicked() {
-			*self.on = !*self.on;
+		self.show(ui, true).0
+	}
+}
+
+impl Switch<'_>
+{
+	/// Shows this switch, returning the value the user asked for, which is only written to the value if `apply` is true.
+	fn show(mut self, ui: &mut Ui, apply: bool) -> (Response, Option<Option<bool>>)
+	{
+		let spacing = ui.spacing().icon_spacing;
+		let text = self.text.map(|text| text.into_galley(ui, Some(false), ui.available_width(), TextStyle::Button));
+		let text_width = text.as_ref().map_or(0., |text| text.size().x + spacing);
+
+		// Expanding switches without a size of their own fill the space a justified layout gives them, such as by `WidgetPlacement::size`
+		let height = self.height.unwrap_or(if self.expand && ui.layout().vertical_justify() { ui.available_height() } else { ui.spacing().interact_size.y });
+		let width = self.width.unwrap_or(if self.expand && ui.layout().horizontal_justify() { ui.available_width() - text_width } else { height * 2. });
+
+		let desired_size = vec2(width + text_width, height.max(text.as_ref().map_or(0., |text| text.size().y)));
+		let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+
+		// The label goes on one side, the track on the other
+		let track_rect = Rect::from_center_size(pos2(if self.text_left { rect.right() - width / 2. } else { rect.left() + width / 2. }, rect.center().y), vec2(width, height));
+
+		// Where the center of the knob can be, from off to on
+		let knob_range = |rect: Rect| {
+			let knob_inset = self.knob_padding.map_or(rect.height() / 2., |padding| padding + self.knob_ratio * rect.height() / 2.);
+			(rect.left() + knob_inset)..=(rect.right() - knob_inset)
+		};
+		// While the knob is being dragged, this is how far it is between off and on
+		let pointer_position = || ui.input(|input| input.pointer.interact_pos()).map(|pointer| remap_clamp(pointer.x, knob_range(track_rect), 0.0..=1.0));
+		let dragged_to = (!self.pending && response.dragged() && ui.input(|input| input.pointer.is_decidedly_dragging())).then(pointer_position).flatten();
+
+		// Clicking (or pressing space or enter while focused) toggles the switch, releasing the knob snaps it to the closest side
+		// Pending switches ignore all input
+		let new_on = if self.pending { None }
+			else if response.clicked() { Some(self.value.next()) }
+			else if response.drag_released() { pointer_position().map(|position| Some(position >= 0.5)) }
+			else { None };
+		let requested = new_on.filter(|&new_on| new_on != self.value.get());
+		if let Some(new_on) = requested.filter(|_| apply) {
+			self.value.set(new_on);
 			response.mark_changed();
 		}
-		response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, *self.on, ""));
-	
+
+		// Indeterminate switches are neither selected nor unselected
+		let on = self.value.get();
+		response.widget_info(|| {
+			let label = text.as_ref().map_or("", |text| text.text());
+			match on {
+				Some(on) => WidgetInfo::selected(WidgetType::Checkbox, on, label),
+				None => WidgetInfo::labeled(WidgetType::Checkbox, if label.is_empty() { "mixed".to_owned() } else { format!("{label}, mixed") }),
+			}
+		});
+
 		if ui.is_rect_visible(rect) {
-			let how_on = ui.ctx().animate_bool(response.id, *self.on);
-			let visuals = ui.style().interact_selectable(&response, *self.on);
-			let rect = rect.expand(visuals.expansion);
+			if let Some(text) = text {
+				let text_pos = pos2(if self.text_left { rect.left() } else { track_rect.right() + spacing }, rect.center().y - text.size().y / 2.);
+				text.paint_with_visuals(ui.painter(), text_pos, ui.style().interact(&response));
+			}
+
+			let how_on = match dragged_to {
+				Some(position) => ui.ctx().animate_value_with_time(response.id, position, 0.),
+				None => ui.ctx().animate_value_with_time(response.id, match on { Some(true) => 1., Some(false) => 0., None => 0.5 }, ui.style().animation_time),
+			};
+			let visuals = ui.style().interact_selectable(&response, on == Some(true));
+			let style = self.style.unwrap_or_else(|| SwitchStyle::global(ui.ctx()));
+			let switch_visuals = style.get_state(response.get_widget_state());
+
+			// The track fades between its off and on fill along with the knob
+			let off_fill = switch_visuals.track_off_fill.unwrap_or_else(|| ui.style().interact_selectable(&response, false).bg_fill);
+			let on_fill = switch_visuals.track_on_fill.unwrap_or_else(|| ui.style().interact_selectable(&response, true).bg_fill);
+			let track_fill: Color32 = lerp(Rgba::from(off_fill)..=Rgba::from(on_fill), how_on).into();
+
+			let rect = track_rect.expand(visuals.expansion);
 			let radius = 0.5 * rect.height();
+			let rounding = self.track_rounding.unwrap_or(Rounding::same(radius));
 			ui.painter()
-				.rect(rect, radius, visuals.bg_fill, visuals.bg_stroke);
-			let circle_x = lerp((rect.left() + radius)..=(rect.right() - radius), how_on);
+				.rect(rect, rounding, track_fill, visuals.bg_stroke);
+			if response.has_focus() {
+				ui.painter().rect_stroke(rect.expand(2.), rounding, ui.visuals().selection.stroke);
+			}
+
+			let knob_radius = self.knob_ratio * radius;
+			let knob_range = knob_range(rect);
+			let knob_inset = knob_range.start() - rect.left();
+			let circle_x = lerp(knob_range.clone(), how_on);
+
+			// The track text fades between the off and on text, each taking up the space on the opposite side of the knob
+			if let Some((off, on)) = self.track_text {
+				for (text, how_shown, area) in [
+					(off, 1. - how_on, Rect::from_min_max(pos2(rect.left() + knob_inset * 2., rect.top()), rect.max)),
+					(on, how_on, Rect::from_min_max(rect.min, pos2(rect.right() - knob_inset * 2., rect.bottom()))),
+				] {
+					if how_shown <= 0. { continue }
+					let galley = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Small);
+					let pos = area.center() - galley.size() / 2.;
+					galley.paint_with_color_override(ui.painter(), pos, visuals.fg_stroke.color.gamma_multiply(how_shown));
+				}
+			}
+
+			if let Some((off, on)) = self.icons {
+				for (icon, x) in [(off, *knob_range.start()), (on, *knob_range.end())] {
+					let galley = icon.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Small);
+					let pos = pos2(x, rect.center().y) - galley.size() / 2.;
+					galley.paint_with_color_override(ui.painter(), pos, visuals.fg_stroke.color);
+				}
+			}
+
 			let center = pos2(circle_x, rect.center().y);
 			ui.painter()
-				.circle(center, 0.75 * radius, visuals.bg_fill, visuals.fg_stroke);
+				.circle(center, knob_radius, switch_visuals.knob_fill.unwrap_or(visuals.bg_fill), switch_visuals.knob_stroke.unwrap_or(visuals.fg_stroke));
+
+			if self.pending {
+				let knob_rect = Rect::from_center_size(center, Vec2::splat(knob_radius * 2.));
+				ui.child_ui(knob_rect, Layout::centered_and_justified(Direction::LeftToRight))
+					.add(Spinner::new().size(knob_radius * 1.5).color(switch_visuals.knob_stroke.unwrap_or(visuals.fg_stroke).color));
+			}
+		}
+
+		(response, requested)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use crate::test_harness::Harness;
+
+	#[derive(Debug, Clone, PartialEq)]
+	enum Theme { Light, Dark }
+
+	#[test]
+	fn size_ignores_justified_layouts_unless_expanding()
+	{
+		let mut harness = Harness::new();
+		// The size of the track painted by the switch, the only rectangle smaller than the panel
+		let mut track_size = |add_switch: &dyn Fn(&mut Ui)| {
+			harness.frame(vec![], |ui| add_switch(ui));
+			harness.shapes().iter().find_map(|shape| match &shape.1 {
+				Shape::Rect(rect) if rect.rect.height() < 100. => Some(rect.rect.size()),
+				_ => None,
+			}).unwrap()
+		};
+
+		let height = Style::default().spacing.interact_size.y;
+		let justified = Layout::top_down_justified(Align::Min);
+		assert_eq!(track_size(&|ui| { ui.with_layout(justified, |ui| ui.add(Switch::new(&mut false))); }), vec2(height * 2., height));
+		assert!(track_size(&|ui| { ui.with_layout(justified, |ui| ui.add(Switch::new(&mut false).expand(true))); }).x > 700.);
+		assert_eq!(track_size(&|ui| { Switch::new(&mut false).expand(true).place().size([60., 30.]).ui(ui); }), vec2(60., 30.));
+	}
+
+	#[test]
+	fn clicking_label_toggles()
+	{
+		let mut harness = Harness::new();
+		let mut on = false;
+		let mut show = |ui: &mut Ui| ui.add(Switch::new(&mut on).text("Label"));
+
+		let response = harness.frame(vec![], &mut show);
+		harness.click(response.rect.right_center() - vec2(5., 0.), &mut show);
+		drop(show);
+		assert!(on);
+	}
+
+	#[test]
+	fn global_style()
+	{
+		let mut style = SwitchStyle::default();
+		style.modify_all(|visuals| visuals.track_on_fill = Some(Color32::RED));
+
+		let ctx = Context::default();
+		assert_eq!(SwitchStyle::global(&ctx), SwitchStyle::default());
+		style.set_global(&ctx);
+		assert_eq!(SwitchStyle::global(&ctx), style);
+	}
+
+	#[test]
+	fn dragging_knob_turns_on()
+	{
+		let mut harness = Harness::new();
+		let mut on = false;
+		let mut show = |ui: &mut Ui| ui.add(Switch::new(&mut on));
+
+		let rect = harness.frame(vec![], &mut show).rect;
+		harness.drag(rect.left_center() + vec2(5., 0.), rect.right_center() + vec2(20., 0.), &mut show);
+		drop(show);
+		assert!(on);
+	}
+
+	#[test]
+	fn tri_state_cycles()
+	{
+		let mut harness = Harness::new();
+		let mut on = None;
+		let mut states = Vec::new();
+
+		let center = harness.frame(vec![], |ui| ui.add(Switch::tri_state(&mut on))).rect.center();
+		for _ in 0..3 {
+			harness.click(center, |ui| ui.add(Switch::tri_state(&mut on)));
+			states.push(on);
 		}
-	
-		response
+		assert_eq!(states, [Some(true), Some(false), None]);
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn pending_and_requests_leave_value()
+	{
+		let mut harness = Harness::new();
+		let mut on = false;
+
+		let center = harness.frame(vec![], |ui| ui.add(Switch::new(&mut on).pending(true))).rect.center();
+		harness.click(center, |ui| ui.add(Switch::new(&mut on).pending(true)));
+		assert!(!on);
+
+		let (_, requested) = harness.click(center, |ui| Switch::new(&mut on).show_request(ui));
+		assert_eq!(requested, Some(true));
+		assert!(!on);
+	}
+
+	#[test]
+	fn between_toggles_values()
+	{
+		let mut harness = Harness::new();
+		let mut theme = Theme::Light;
+		let mut show = |ui: &mut Ui| ui.add(Switch::between(&mut theme, Theme::Light, Theme::Dark));
+
+		let center = harness.frame(vec![], &mut show).rect.center();
+		harness.click(center, &mut show);
+		drop(show);
+		assert_eq!(theme, Theme::Dark);
+	}
+}
@@ -0,0 +1,66 @@
+use crate::*;
+
+/// Runs frames of a [Context] with the given input, so tests can click and drag things like a user would.
+pub(crate) struct Harness
+{
+	ctx: Context,
+	time: f64,
+	pointer: Pos2,
+	shapes: Vec<epaint::ClippedShape>,
+}
+
+impl Harness
+{
+	pub(crate) fn new() -> Self {
+		Self { ctx: Context::default(), time: 0., pointer: Pos2::ZERO, shapes: Vec::new() }
+	}
+
+	pub(crate) fn frame<R>(&mut self, events: Vec<Event>, mut add_contents: impl FnMut(&mut Ui) -> R) -> R {
+		self.time += 1. / 60.;
+		let input = RawInput {
+			screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800., 600.))),
+			time: Some(self.time),
+			events,
+			..Default::default()
+		};
+
+		let mut output = None;
+		self.shapes = self.ctx.run(input, |ctx| { CentralPanel::default().show(ctx, |ui| output = Some(add_contents(ui))); }).shapes;
+		output.unwrap()
+	}
+
+	/// What was painted in the last frame.
+	pub(crate) fn shapes(&self) -> &[epaint::ClippedShape] {
+		&self.shapes
+	}
+
+	pub(crate) fn button_event(&self, pressed: bool) -> Event {
+		Event::PointerButton { pos: self.pointer, button: PointerButton::Primary, pressed, modifiers: Modifiers::NONE }
+	}
+
+	/// Moves the pointer to `pos` over a few frames, pressing or releasing the primary button first if set.
+	pub(crate) fn move_to<R>(&mut self, pos: Pos2, button: Option<bool>, mut add_contents: impl FnMut(&mut Ui) -> R) -> R {
+		if let Some(pressed) = button { self.frame(vec![self.button_event(pressed)], &mut add_contents); }
+		let start = self.pointer;
+		for t in [0.25, 0.5, 0.75] {
+			self.pointer = start.lerp(pos, t);
+			self.frame(vec![Event::PointerMoved(self.pointer)], &mut add_contents);
+		}
+		self.pointer = pos;
+		self.frame(vec![Event::PointerMoved(pos)], &mut add_contents)
+	}
+
+	/// Clicks at `pos`, returning what the frame the button was released in returned.
+	pub(crate) fn click<R>(&mut self, pos: Pos2, mut add_contents: impl FnMut(&mut Ui) -> R) -> R {
+		self.move_to(pos, None, &mut add_contents);
+		self.frame(vec![self.button_event(true)], &mut add_contents);
+		self.frame(vec![self.button_event(false)], &mut add_contents)
+	}
+
+	/// Drags from `from` to `to`, returning what the frame the button was released in returned.
+	pub(crate) fn drag<R>(&mut self, from: Pos2, to: Pos2, mut add_contents: impl FnMut(&mut Ui) -> R) -> R {
+		self.move_to(from, None, &mut add_contents);
+		self.move_to(to, Some(true), &mut add_contents);
+		self.frame(vec![self.button_event(false)], &mut add_contents)
+	}
+}